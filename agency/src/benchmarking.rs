@@ -8,8 +8,9 @@ use frame_benchmarking::{
 	account, benchmarks, benchmarks_instance, benchmarks_instance_pallet,
 	impl_benchmark_test_suite, whitelisted_caller,
 };
+use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
 use frame_system::{Call as SystemCall, RawOrigin as SystemOrigin};
-use primitives::AccountIdConversion;
+use primitives::{constant::MAX_PRESET_NAME_LENGTH, AccountIdConversion};
 
 fn get_alice<T: Config<I>, I: 'static>() -> T::AccountId {
 	account("alice", 1, 1)
@@ -35,9 +36,16 @@ fn create_dao<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId) {
 	(dao_id, second_id)
 }
 
-fn get_proposal<T: Config<I>, I: 'static>(dao_id: T::DaoId) -> (T::Proposal, T::Hash) {
+fn get_proposal<T: Config<I>, I: 'static>(dao_id: T::DaoId) -> (PreimageRef<T::Hash>, T::Hash) {
 	let proposal: T::Proposal = DaoCall::<T>::dao_remark { dao_id, remark: vec![1; 20] }.into();
-	(proposal.clone(), T::Hashing::hash_of(&proposal))
+	let bytes = proposal.encode();
+	let preimage = PreimageRef { hash: T::Hashing::hash(&bytes[..]), len: bytes.len() as u32 };
+	let alice = get_alice::<T, I>();
+	let amount = dao::PreimageBalanceOf::<T>::max_value() / 2u32.into();
+	T::PreimageCurrency::make_free_balance_be(&alice, amount);
+	let origin = SystemOrigin::Signed(alice).into();
+	assert!(dao::Pallet::<T>::note_preimage(origin, dao_id, bytes).is_ok());
+	(preimage, preimage.hash)
 }
 
 fn create_proposal<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId, T::Hash, ProposalIndex)
@@ -47,8 +55,11 @@ fn create_proposal<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId, T::H
 	assert!(Collective::<T, I>::propose(
 		SystemOrigin::Signed(get_alice::<T, I>()).into(),
 		dao_id,
+		DEFAULT_COMMITTEE,
 		2 as ProposalIndex,
-		Box::new(proposal)
+		proposal,
+		None,
+		None
 	)
 	.is_ok());
 	(dao_id, second_id, proposal_hash, 0 as ProposalIndex)
@@ -60,7 +71,6 @@ fn user_vote<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId, T::Hash, P
 	assert!(Collective::<T, I>::vote(
 		SystemOrigin::Signed(dao_account).into(),
 		dao_id,
-		proposal_hash,
 		index,
 		true
 	)
@@ -68,6 +78,31 @@ fn user_vote<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId, T::Hash, P
 	(dao_id, second_id, proposal_hash, index)
 }
 
+fn cool_off<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId, T::Hash, ProposalIndex) {
+	let (dao_id, second_id, proposal_hash, index) = user_vote::<T, I>();
+	VetoHolder::<T, I>::insert(dao_id, get_alice::<T, I>());
+	assert!(Collective::<T, I>::close(
+		SystemOrigin::Signed(get_alice::<T, I>()).into(),
+		dao_id,
+		index
+	)
+	.is_ok());
+	(dao_id, second_id, proposal_hash, index)
+}
+
+fn add_committee<T: Config<I>, I: 'static>() -> (T::DaoId, T::ConcreteId, CommitteeId) {
+	let (dao_id, second_id) = create_dao::<T, I>();
+	let dao_account = get_dao_account::<T, I>(second_id.clone());
+	assert!(Collective::<T, I>::create_committee(
+		SystemOrigin::Signed(dao_account).into(),
+		dao_id,
+		vec![1; 20],
+		2 as MemberCount
+	)
+	.is_ok());
+	(dao_id, second_id, DEFAULT_COMMITTEE + 1)
+}
+
 benchmarks_instance_pallet! {
 	execute {
 		let alice = get_alice::<T, I>();
@@ -77,26 +112,46 @@ benchmarks_instance_pallet! {
 			remark: vec![1; 20],
 		}.into();
 
-	}:_(SystemOrigin::Signed(get_dao_account::<T, I>(second_id)), dao_id, Box::new(proposal))
+	}:_(SystemOrigin::Signed(get_dao_account::<T, I>(second_id)), dao_id, DEFAULT_COMMITTEE, Box::new(proposal))
 
 	propose {
 		let (dao_id, second_id) = create_dao::<T, I>();
 		let (proposal, proposal_hash) = get_proposal::<T, I>(dao_id);
-	}:_(SystemOrigin::Signed(get_alice::<T, I>()), dao_id, 2 as ProposalIndex, Box::new(proposal))
+	}:_(SystemOrigin::Signed(get_alice::<T, I>()), dao_id, DEFAULT_COMMITTEE, 2 as ProposalIndex, proposal, Some(vec![1; 20]), Some(vec![1; 46]))
 
 	vote {
 		let (dao_id, second_id, proposal_hash, index) = create_proposal::<T, I>();
 		let dao_account = get_dao_account::<T, I>(second_id);
-	}:_(SystemOrigin::Signed(dao_account), dao_id, proposal_hash, index, true)
+	}:_(SystemOrigin::Signed(dao_account), dao_id, index, true)
+
+	abstain {
+		let (dao_id, second_id, proposal_hash, index) = create_proposal::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, index)
 
 	close {
 		let (dao_id, second_id, proposal_hash, index) = user_vote::<T, I>();
-	}:_(SystemOrigin::Signed(get_alice::<T, I>()), dao_id, proposal_hash, index)
+	}:_(SystemOrigin::Signed(get_alice::<T, I>()), dao_id, index)
+
+	delegate {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, get_alice::<T, I>())
+
+	undelegate {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+		Delegations::<T, I>::insert(dao_id, dao_account.clone(), get_alice::<T, I>());
+	}:_(SystemOrigin::Signed(dao_account), dao_id)
+
+	withdraw {
+		let (dao_id, second_id, proposal_hash, index) = create_proposal::<T, I>();
+	}:_(SystemOrigin::Signed(get_alice::<T, I>()), dao_id, index)
 
 	disapprove_proposal {
 		let (dao_id, second_id, proposal_hash, index) = create_proposal::<T, I>();
 		let dao_account = get_dao_account::<T, I>(second_id);
-	}:_(SystemOrigin::Signed(dao_account), dao_id, proposal_hash)
+	}:_(SystemOrigin::Signed(dao_account), dao_id, index)
 
 	set_motion_duration {
 		let (dao_id, second_id) = create_dao::<T, I>();
@@ -117,4 +172,84 @@ benchmarks_instance_pallet! {
 		let (dao_id, second_id) = create_dao::<T, I>();
 		let dao_account = get_dao_account::<T, I>(second_id);
 	}:_(SystemOrigin::Signed(dao_account), dao_id, T::CallId::default(), DoAsEnsureOrigin::Member)
+
+	veto {
+		let (dao_id, second_id, proposal_hash, index) = cool_off::<T, I>();
+	}:_(SystemOrigin::Signed(get_alice::<T, I>()), dao_id, index)
+
+	set_veto_holder {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, Some(get_alice::<T, I>()))
+
+	set_cooling_off_period {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, 100u32)
+
+	set_blacklist_period {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, 100u32)
+
+	create_committee {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, vec![1; 20], 2 as MemberCount)
+
+	set_committee_members {
+		let (dao_id, second_id, committee_id) = add_committee::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account.clone()), dao_id, committee_id, vec![get_alice::<T, I>(), dao_account.clone()])
+
+	set_committee_threshold {
+		let (dao_id, second_id, committee_id) = add_committee::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, committee_id, 3 as MemberCount)
+
+	set_committee_ensure_origin {
+		let (dao_id, second_id, committee_id) = add_committee::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, committee_id, T::CallId::default(), DoAsEnsureOrigin::Member)
+
+	set_default_vote_policy {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+	}:_(SystemOrigin::Signed(dao_account), dao_id, DefaultVotePolicy::AbstainAsNay)
+
+	set_ensure_origin_tiers {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+		let tiers = (0..MAX_MAGNITUDE_TIERS)
+			.map(|i| (i as u128 * 1000, DoAsEnsureOrigin::Members(i)))
+			.collect::<Vec<_>>();
+	}:_(SystemOrigin::Signed(dao_account), dao_id, DEFAULT_COMMITTEE, T::CallId::default(), tiers)
+
+	set_ensure_origins {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+		let origins = vec![(T::CallId::default(), DoAsEnsureOrigin::Member); MAX_PRESET_CALLS as usize];
+	}:_(SystemOrigin::Signed(dao_account), dao_id, origins)
+
+	register_ensure_origin_preset {
+		let origins = vec![(T::CallId::default(), DoAsEnsureOrigin::Member); MAX_PRESET_CALLS as usize];
+	}:_(SystemOrigin::Root, vec![1; MAX_PRESET_NAME_LENGTH as usize], origins)
+
+	apply_ensure_origin_preset {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+		let origins = vec![(T::CallId::default(), DoAsEnsureOrigin::Member); MAX_PRESET_CALLS as usize];
+		assert!(Collective::<T, I>::register_ensure_origin_preset(
+			SystemOrigin::Root.into(),
+			vec![1; MAX_PRESET_NAME_LENGTH as usize],
+			origins
+		)
+		.is_ok());
+	}:_(SystemOrigin::Signed(dao_account), dao_id, vec![1; MAX_PRESET_NAME_LENGTH as usize])
+
+	set_allowance {
+		let (dao_id, second_id) = create_dao::<T, I>();
+		let dao_account = get_dao_account::<T, I>(second_id);
+		let allowance = Allowance { period: 100, max_executions: 10, max_value: Some(1000) };
+	}:_(SystemOrigin::Signed(dao_account), dao_id, get_alice::<T, I>(), Some(allowance))
 }
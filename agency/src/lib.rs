@@ -21,7 +21,7 @@
 // In their place is the EnsureOriginWithArg.
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![recursion_limit = "128"]
+#![recursion_limit = "256"]
 #![allow(clippy::tabs_in_doc_comments)]
 
 //! # Agency Module
@@ -42,34 +42,49 @@
 //!
 //! let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
 //! 			dao_id: 0u64,
+//! 			committee_id: 0u32,
 //! 			call: Box::new(set_max_members),
 //! 		});
 //!
 //! assert!(crate::Pallet::<Test>::execute(
 //! 			Origin::signed(ALICE),
 //! 			0u64,
+//! 			0u32,
 //! 			Box::new(do_as_agency)
 //! 		)
 //! 		.is_ok());
 //! ***
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	dispatch::{DispatchResultWithPostInfo, PostDispatchInfo, GetDispatchInfo},
+	dispatch::{DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo, GetDispatchInfo},
 	ensure,
-	traits::{Get, StorageVersion},
+	traits::{Get, IsSubType, IsType, PalletInfoAccess, StorageVersion},
 	weights::{Weight},
+	BoundedVec,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use primitives::{
-	traits::{EnsureOriginWithArg, SetCollectiveMembers},
-	types::{DoAsEnsureOrigin, MemberCount, Proportion, ProposalIndex},
+	constant::{
+		MAX_ENSURE_ORIGIN_DEPTH, MAX_MAGNITUDE_TIERS, MAX_PRESET_CALLS, MAX_SIGNED_VOTES,
+		SIGNED_VOTE_CONTEXT,
+	},
+	traits::{CallMagnitude, EnsureOriginWithArg, SetCollectiveMembers},
+	types::{
+		CommitteeId, DescriptionHash, DoAsEnsureOrigin, MemberCount, PreimageRef, PresetName,
+		ProposalIndex, ProposalMetadata, Proportion,
+	},
 };
 
 use frame_support::sp_runtime::traits::Hash;
+use traits::OnMembersChanged as _;
 use frame_support::pallet_prelude::DispatchError;
 pub use scale_info::{prelude::boxed::Box, TypeInfo};
-use sp_runtime::{RuntimeDebug, traits::Dispatchable};
+use sp_runtime::{
+	traits::{Dispatchable, IdentifyAccount, Verify, Zero},
+	RuntimeDebug, SaturatedConversion,
+};
 use sp_std::{marker::PhantomData, prelude::*, result};
 use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
@@ -78,9 +93,13 @@ mod benchmarking;
 mod mock;
 #[cfg(test)]
 mod tests;
+pub mod runtime_api;
 pub mod traits;
 pub mod weights;
 
+/// The committee every DAO starts with, whose members are set through `SetCollectiveMembers`.
+pub const DEFAULT_COMMITTEE: CommitteeId = 0;
+
 /// Default voting strategy when a member is inactive.
 pub trait DefaultVote {
 	/// Get the default voting strategy, given:
@@ -127,14 +146,74 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 	}
 }
 
+/// How a DAO counts the members who did not vote when a motion is closed.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub enum DefaultVotePolicy {
+	/// Members who did not vote follow the prime, see `PrimeDefaultVote`.
+	#[default]
+	PrimeDecides,
+	/// Members who did not vote follow the majority, or else the prime, see
+	/// `MoreThanMajorityThenPrimeDefaultVote`.
+	MajorityThenPrime,
+	/// Members who did not vote count as nay.
+	AbstainAsNay,
+	/// Members who did not vote are left out, and the threshold applies to the share of the
+	/// members who voted.
+	AbstainIgnored,
+}
+
+impl DefaultVotePolicy {
+	/// Get the default vote of the members who did not vote, see `DefaultVote`.
+	pub fn default_vote(
+		&self,
+		prime_vote: Option<bool>,
+		yes_votes: MemberCount,
+		no_votes: MemberCount,
+		len: MemberCount,
+	) -> bool {
+		match self {
+			Self::PrimeDecides =>
+				PrimeDefaultVote::default_vote(prime_vote, yes_votes, no_votes, len),
+			Self::MajorityThenPrime => MoreThanMajorityThenPrimeDefaultVote::default_vote(
+				prime_vote, yes_votes, no_votes, len,
+			),
+			Self::AbstainAsNay | Self::AbstainIgnored => false,
+		}
+	}
+}
+
+/// How much a member can execute alone with the `Member` origin in each period.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Allowance {
+	/// The length of a period in blocks.
+	pub period: u32,
+	/// The maximum number of executions in a period.
+	pub max_executions: u32,
+	/// The maximum total magnitude of the executed calls in a period, such as the amount spent.
+	pub max_value: Option<u128>,
+}
+
+/// What a member used of their allowance in the current period.
+#[derive(
+	PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct AllowanceUsage {
+	/// The block the period started at.
+	pub period_start: u32,
+	/// The number of executions in the period.
+	pub executions: u32,
+	/// The total magnitude of the calls executed in the period.
+	pub value: u128,
+}
+
 /// Origin for the collective module.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
 #[scale_info(skip_type_params(I))]
 pub enum RawOrigin<DaoId, I> {
-	/// It has been condoned by a given number of members of the collective from a given total.
-	Members(DaoId, MemberCount, MemberCount),
-	/// It has been condoned by a single member of the collective.
-	Member(DaoId),
+	/// It has been condoned by a given number of members of a committee from a given total.
+	Members(DaoId, CommitteeId, MemberCount, MemberCount),
+	/// It has been condoned by a single member of a committee.
+	Member(DaoId, CommitteeId),
 	/// Collective does not have execute permission.
 	Root(DaoId),
 	/// Dummy to manage the fact we have instancing.
@@ -152,8 +231,21 @@ pub struct Votes<AccountId, BlockNumber> {
 	ayes: Vec<AccountId>,
 	/// The current set of voters that rejected it.
 	nays: Vec<AccountId>,
+	/// The current set of voters that explicitly abstained.
+	abstentions: Vec<AccountId>,
 	/// The hard end time of this vote.
 	end: BlockNumber,
+	/// The committee voting on the motion.
+	committee: CommitteeId,
+}
+
+/// A named committee created by a DAO at runtime.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CommitteeInfo<Name> {
+	/// The name of the committee, such as `tech` or `finance`.
+	pub name: Name,
+	/// The least number of approvals a motion of the committee must require.
+	pub threshold: MemberCount,
 }
 
 #[frame_support::pallet]
@@ -173,7 +265,9 @@ pub mod pallet {
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
-	pub trait Config<I: 'static = ()>: frame_system::Config + dao::Config {
+	pub trait Config<I: 'static = ()>:
+		frame_system::Config + dao::Config + daos_doas::Config
+	{
 		/// The outer origin type.
 		type Origin: From<RawOrigin<<Self as dao::Config>::DaoId, I>>
 			+ Into<
@@ -181,7 +275,12 @@ pub mod pallet {
 					RawOrigin<<Self as dao::Config>::DaoId, I>,
 					<Self as pallet::Config<I>>::Origin,
 				>,
-			>;
+			> + Into<
+				Result<
+					frame_system::RawOrigin<Self::AccountId>,
+					<Self as pallet::Config<I>>::Origin,
+				>,
+			> + Clone;
 
 		/// The outer event type.
 		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -192,19 +291,38 @@ pub mod pallet {
 			+ From<frame_system::Call<Self>>
 			+ From<Call<Self, I>>
 			+ From<dao::Call<Self>>
+			+ IsSubType<daos_doas::Call<Self>>
 			+ IsType<<Self as frame_system::Config>::RuntimeCall>
 			+ GetDispatchInfo;
 
 		/// External transactions that collectives can execute directly.
 		type CollectiveBaseCallFilter: Contains<Self::Proposal>;
 
-		/// Default vote strategy of this collective.
-		type DefaultVote: DefaultVote;
-
 		/// Collective in DAO Maximum number of people.
 		#[pallet::constant]
 		type MaxMembersForSystem: Get<MemberCount>;
 
+		/// The maximum length of a motion's title.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+
+		/// The maximum length of a committee's name.
+		#[pallet::constant]
+		type MaxCommitteeNameLength: Get<u32>;
+
+		/// The maximum number of due motions closed automatically in a block.
+		#[pallet::constant]
+		type MaxClosesPerBlock: Get<u32>;
+
+		/// What to do when the members of a committee change.
+		type OnMembersChanged: traits::OnMembersChanged<Self::DaoId, Self::AccountId>;
+
+		/// The signature of a vote signed off-chain.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key that signs votes off-chain.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -213,11 +331,52 @@ pub mod pallet {
 	#[pallet::origin]
 	pub type Origin<T, I = ()> = RawOrigin<<T as dao::Config>::DaoId, I>;
 
-	/// The hashes of the active proposals.
+	/// The origin a call needs.
+	pub type DoAsEnsureOriginOf<T> = DoAsEnsureOrigin<
+		Proportion<MemberCount>,
+		MemberCount,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	/// The origins a call needs from a given magnitude on, in ascending order of magnitude.
+	pub type EnsureOriginTiersOf<T> =
+		BoundedVec<(u128, DoAsEnsureOriginOf<T>), ConstU32<MAX_MAGNITUDE_TIERS>>;
+
+	/// The origins of some calls, set at once.
+	pub type EnsureOriginsOf<T> = BoundedVec<
+		(<T as dao::Config>::CallId, DoAsEnsureOriginOf<T>),
+		ConstU32<MAX_PRESET_CALLS>,
+	>;
+
+	/// A vote signed off-chain by a member: the member, the motion, the vote and the signature.
+	pub type SignedVoteOf<T, I = ()> = (
+		<T as frame_system::Config>::AccountId,
+		ProposalIndex,
+		bool,
+		<T as Config<I>>::OffchainSignature,
+	);
+
+	/// The title and description of a motion.
+	pub type BoundedMetadataOf<T, I = ()> =
+		ProposalMetadata<BoundedVec<u8, <T as Config<I>>::MaxTitleLength>, DescriptionHash>;
+
+	/// The information of a named committee.
+	pub type CommitteeInfoOf<T, I = ()> =
+		CommitteeInfo<BoundedVec<u8, <T as Config<I>>::MaxCommitteeNameLength>>;
+
+	/// The indexes of the active proposals.
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
 	pub type Proposals<T: Config<I>, I: 'static = ()> =
-		StorageMap<_, Identity, T::DaoId, Vec<T::Hash>, ValueQuery>;
+		StorageMap<_, Identity, T::DaoId, Vec<ProposalIndex>, ValueQuery>;
+
+	/// The indexes of the active proposals of a given call hash.
+	///
+	/// The same call can be proposed again while it is still active.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_indexes)]
+	pub type ProposalIndexes<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, T::Hash, Vec<ProposalIndex>, ValueQuery>;
 
 	/// The origin of each call.
 	#[pallet::storage]
@@ -228,7 +387,86 @@ pub mod pallet {
 		<T as dao::Config>::DaoId,
 		Blake2_128Concat,
 		<T as dao::Config>::CallId,
-		DoAsEnsureOrigin<Proportion<MemberCount>, MemberCount>,
+		DoAsEnsureOriginOf<T>,
+		ValueQuery,
+	>;
+
+	/// The origins of calls a template registers under a name, for DAOs to apply at once.
+	#[pallet::storage]
+	#[pallet::getter(fn ensure_origin_presets)]
+	pub type EnsureOriginPresets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PresetName, EnsureOriginsOf<T>>;
+
+	/// The origin of each call for a named committee.
+	#[pallet::storage]
+	#[pallet::getter(fn committee_ensures)]
+	pub type CommitteeEnsureOrigins<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128, <T as dao::Config>::DaoId>,
+			NMapKey<Identity, CommitteeId>,
+			NMapKey<Blake2_128Concat, <T as dao::Config>::CallId>,
+		),
+		DoAsEnsureOriginOf<T>,
+		ValueQuery,
+	>;
+
+	/// The origin of each call from a given magnitude on, for each committee.
+	///
+	/// A call whose magnitude is below every tier needs its origin in `EnsureOrigins` or
+	/// `CommitteeEnsureOrigins`.
+	#[pallet::storage]
+	#[pallet::getter(fn ensure_origin_tiers)]
+	pub type EnsureOriginTiers<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128, <T as dao::Config>::DaoId>,
+			NMapKey<Identity, CommitteeId>,
+			NMapKey<Blake2_128Concat, <T as dao::Config>::CallId>,
+		),
+		EnsureOriginTiersOf<T>,
+		ValueQuery,
+	>;
+
+	/// The named committees of each DAO.
+	#[pallet::storage]
+	#[pallet::getter(fn committees)]
+	pub type Committees<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		<T as dao::Config>::DaoId,
+		Identity,
+		CommitteeId,
+		CommitteeInfoOf<T, I>,
+	>;
+
+	#[pallet::type_value]
+	pub fn NextCommitteeIdOnEmpty<T: Config<I>, I: 'static>() -> CommitteeId {
+		DEFAULT_COMMITTEE + 1
+	}
+
+	/// The id of the next named committee of each DAO.
+	#[pallet::storage]
+	#[pallet::getter(fn next_committee_id)]
+	pub type NextCommitteeId<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Identity,
+		<T as dao::Config>::DaoId,
+		CommitteeId,
+		ValueQuery,
+		NextCommitteeIdOnEmpty<T, I>,
+	>;
+
+	/// All members of a named committee.
+	#[pallet::storage]
+	#[pallet::getter(fn committee_members)]
+	pub type CommitteeMembers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		<T as dao::Config>::DaoId,
+		Identity,
+		CommitteeId,
+		Vec<T::AccountId>,
 		ValueQuery,
 	>;
 
@@ -277,7 +515,81 @@ pub mod pallet {
 	pub type MaxMembers<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, T::DaoId, MemberCount, ValueQuery, MaxMembersOnEmpty<T, I>>;
 
-	/// Actual proposal for a given hash, if it's current.
+	/// How each DAO counts the members who did not vote.
+	#[pallet::storage]
+	#[pallet::getter(fn default_vote_policy)]
+	pub type DefaultVoteOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::DaoId, DefaultVotePolicy, ValueQuery>;
+
+	/// How much each member can execute alone in each DAO, without a limit if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Identity, T::DaoId, Blake2_128Concat, T::AccountId, Allowance>;
+
+	/// What each member used of their allowance in each DAO.
+	#[pallet::storage]
+	#[pallet::getter(fn allowance_usage)]
+	pub type AllowanceUsageOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Blake2_128Concat,
+		T::AccountId,
+		AllowanceUsage,
+		ValueQuery,
+	>;
+
+	/// The member who can veto approved motions in each DAO.
+	#[pallet::storage]
+	#[pallet::getter(fn veto_holder)]
+	pub type VetoHolder<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, <T as dao::Config>::DaoId, T::AccountId>;
+
+	#[pallet::type_value]
+	pub fn CoolingOffPeriodOnEmpty<T: Config<I>, I: 'static>() -> u32 {
+		100u32
+	}
+
+	/// How long an approved motion waits before it can be executed, while a veto holder is set.
+	#[pallet::storage]
+	#[pallet::getter(fn cooling_off_period)]
+	pub type CoolingOffPeriod<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::DaoId, u32, ValueQuery, CoolingOffPeriodOnEmpty<T, I>>;
+
+	#[pallet::type_value]
+	pub fn BlacklistPeriodOnEmpty<T: Config<I>, I: 'static>() -> u32 {
+		500u32
+	}
+
+	/// How long a vetoed motion can not be proposed again.
+	#[pallet::storage]
+	#[pallet::getter(fn blacklist_period)]
+	pub type BlacklistPeriod<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::DaoId, u32, ValueQuery, BlacklistPeriodOnEmpty<T, I>>;
+
+	/// Approved motions that are cooling off.
+	///
+	/// (block from which it can be executed, yes votes, seats)
+	#[pallet::storage]
+	#[pallet::getter(fn cooling_off)]
+	pub type CoolingOff<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		ProposalIndex,
+		(u32, MemberCount, MemberCount),
+		OptionQuery,
+	>;
+
+	/// Vetoed motions and the block until which they can not be proposed again.
+	#[pallet::storage]
+	#[pallet::getter(fn blacklist)]
+	pub type Blacklist<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, T::Hash, u32, OptionQuery>;
+
+	/// The preimage of the proposal for a given index, if it's current.
 	#[pallet::storage]
 	#[pallet::getter(fn proposal_of)]
 	pub type ProposalOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -285,8 +597,34 @@ pub mod pallet {
 		Identity,
 		T::DaoId,
 		Identity,
-		T::Hash,
-		<T as Config<I>>::Proposal,
+		ProposalIndex,
+		PreimageRef<T::Hash>,
+		OptionQuery,
+	>;
+
+	/// The member each member has delegated their vote to.
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Who proposed a given motion, if it's current.
+	#[pallet::storage]
+	#[pallet::getter(fn proposer_of)]
+	pub type ProposerOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		ProposalIndex,
+		T::AccountId,
 		OptionQuery,
 	>;
 
@@ -298,17 +636,103 @@ pub mod pallet {
 		Identity,
 		T::DaoId,
 		Identity,
-		T::Hash,
+		ProposalIndex,
 		Votes<T::AccountId, u32>,
 		OptionQuery,
 	>;
 
+	/// The number of votes a member has signed off-chain in a DAO.
+	///
+	/// It is part of the signed payload, so a signed vote can be submitted only once.
+	#[pallet::storage]
+	#[pallet::getter(fn signed_vote_nonce)]
+	pub type SignedVoteNonce<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The title and description of a given motion, if it's current.
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_of)]
+	pub type MetadataOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		ProposalIndex,
+		BoundedMetadataOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// Motions to be closed automatically at a given block, because their voting period or
+	/// cooling-off period ends there.
+	#[pallet::storage]
+	#[pallet::getter(fn motions_due)]
+	pub type MotionsDue<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, Vec<(T::DaoId, ProposalIndex)>, ValueQuery>;
+
+	/// Due motions over the per block limit, waiting to be closed.
+	#[pallet::storage]
+	#[pallet::getter(fn motions_overdue)]
+	pub type MotionsOverdue<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<(T::DaoId, ProposalIndex)>, ValueQuery>;
+
 	/// Proposals so far.
 	#[pallet::storage]
 	#[pallet::getter(fn proposal_count)]
 	pub type ProposalCount<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, T::DaoId, u32, ValueQuery>;
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Close the overdue motions and then the ones that are due, at most `MaxClosesPerBlock`
+		/// of them.
+		///
+		/// The rest waits in `MotionsOverdue`.
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let now: u32 = n.saturated_into();
+			let mut due = MotionsOverdue::<T, I>::take();
+			due.append(&mut MotionsDue::<T, I>::take(now));
+			let weight = T::DbWeight::get().reads_writes(2, 2);
+			let limit = T::MaxClosesPerBlock::get() as usize;
+			if due.len() > limit {
+				MotionsOverdue::<T, I>::put(due.split_off(limit));
+			}
+			due.into_iter().fold(weight, |weight, (dao_id, index)| {
+				let executed = Self::close_due(dao_id, index);
+				weight
+					.saturating_add(<T as Config<I>>::WeightInfo::close())
+					.saturating_add(executed)
+			})
+		}
+
+		/// Close overdue motions while the weight left in the block covers them and their calls.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut overdue = MotionsOverdue::<T, I>::take();
+			let mut closed = 0;
+			for (dao_id, index) in overdue.iter().copied() {
+				let close = weight.saturating_add(<T as Config<I>>::WeightInfo::close());
+				let call = Self::motion_weight(dao_id, index);
+				if close.saturating_add(call).any_gt(remaining_weight) {
+					break
+				}
+				weight = close.saturating_add(Self::close_due(dao_id, index));
+				closed += 1;
+			}
+			overdue.drain(..closed);
+			if !overdue.is_empty() {
+				MotionsOverdue::<T, I>::put(overdue);
+			}
+			weight
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -319,26 +743,38 @@ pub mod pallet {
 			proposal_index: ProposalIndex,
 			proposal_hash: T::Hash,
 			threshold: MemberCount,
+			metadata: BoundedMetadataOf<T, I>,
 		},
-		/// A motion (given hash) has been voted on by given account, leaving
+		/// A motion (given index) has been voted on by given account, leaving
 		/// a tally (yes votes and no votes given respectively as `MemberCount`).
 		Voted {
 			account: T::AccountId,
-			proposal_hash: T::Hash,
+			proposal_index: ProposalIndex,
 			voted: bool,
 			yes: MemberCount,
 			no: MemberCount,
 		},
+		/// A member explicitly abstained on a motion (given index), leaving a tally (yes, no
+		/// and abstain votes given respectively as `MemberCount`).
+		Abstained {
+			account: T::AccountId,
+			proposal_index: ProposalIndex,
+			yes: MemberCount,
+			no: MemberCount,
+			abstain: MemberCount,
+		},
 		/// A motion was approved by the required threshold.
-		Approved { proposal_hash: T::Hash },
+		Approved { proposal_index: ProposalIndex },
 		/// A motion was not approved by the required threshold.
-		Disapproved { proposal_hash: T::Hash },
+		Disapproved { proposal_index: ProposalIndex },
+		/// A motion was withdrawn by its proposer.
+		Withdrawn { proposal_index: ProposalIndex },
 		/// A motion was executed; result will be `Ok` if it returned without error.
-		Executed { proposal_hash: T::Hash, result: DispatchResult },
+		Executed { proposal_index: ProposalIndex, result: DispatchResult },
 		/// A single member did some action; result will be `Ok` if it returned without error.
 		MemberExecuted { proposal_hash: T::Hash, result: DispatchResult },
 		/// A proposal was closed because its threshold was reached or after its duration was up.
-		Closed { proposal_hash: T::Hash, yes: MemberCount, no: MemberCount },
+		Closed { proposal_index: ProposalIndex, yes: MemberCount, no: MemberCount },
 		/// Set the voting duration for a proposal in each DAO.
 		SetMotionDuration { dao_id: T::DaoId, duration: u32 },
 		/// Set a cap on the number of proposals in each DAO.
@@ -346,7 +782,69 @@ pub mod pallet {
 		/// Set the upper limit of the number of council members in each DAO.
 		SetMaxMembers { dao_id: T::DaoId, max: MemberCount },
 		/// Set Origin for a method in DAO.
-		SetOrigin(T::DaoId, T::CallId, DoAsEnsureOrigin<Proportion<MemberCount>, MemberCount>),
+		SetOrigin(T::DaoId, T::CallId, DoAsEnsureOriginOf<T>),
+		/// An approved motion can be executed once its cooling-off period is over.
+		CoolingOff { proposal_index: ProposalIndex, until: u32 },
+		/// A due motion could not be closed, and was removed.
+		CloseFailed { dao_id: T::DaoId, proposal_index: ProposalIndex, error: DispatchError },
+		/// A motion was vetoed and can not be proposed again until the given block.
+		Vetoed {
+			dao_id: T::DaoId,
+			who: T::AccountId,
+			proposal_index: ProposalIndex,
+			proposal_hash: T::Hash,
+			until: u32,
+		},
+		/// A member delegated their vote to another member.
+		Delegated { dao_id: T::DaoId, who: T::AccountId, to: T::AccountId },
+		/// A member took back their delegated vote.
+		Undelegated { dao_id: T::DaoId, who: T::AccountId },
+		/// Set the member who can veto approved motions in DAO.
+		SetVetoHolder { dao_id: T::DaoId, who: Option<T::AccountId> },
+		/// Set the cooling-off period of approved motions in DAO.
+		SetCoolingOffPeriod { dao_id: T::DaoId, period: u32 },
+		/// Set how long a vetoed motion can not be proposed again in DAO.
+		SetBlacklistPeriod { dao_id: T::DaoId, period: u32 },
+		/// A named committee was created in DAO.
+		CommitteeCreated {
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			name: BoundedVec<u8, T::MaxCommitteeNameLength>,
+		},
+		/// Set the members of a committee in DAO.
+		SetCommitteeMembers {
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			members: Vec<T::AccountId>,
+		},
+		/// Set the least number of approvals the motions of a committee must require.
+		SetCommitteeThreshold {
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			threshold: MemberCount,
+		},
+		/// Set the origins of a method of a committee by the magnitude of the call in DAO.
+		SetOriginTiers {
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			call_id: T::CallId,
+			tiers: EnsureOriginTiersOf<T>,
+		},
+		/// Set how much a member can execute alone in DAO, or remove the limit.
+		SetAllowance { dao_id: T::DaoId, who: T::AccountId, allowance: Option<Allowance> },
+		/// A permission preset was registered, or removed if it has no origins.
+		OriginPresetRegistered { name: PresetName, origins: EnsureOriginsOf<T> },
+		/// A permission preset was applied in DAO.
+		OriginPresetApplied { dao_id: T::DaoId, name: PresetName },
+		/// Set how the members who did not vote are counted in DAO.
+		SetDefaultVotePolicy { dao_id: T::DaoId, policy: DefaultVotePolicy },
+		/// Set Origin for a method of a committee in DAO.
+		SetCommitteeOrigin(
+			T::DaoId,
+			CommitteeId,
+			T::CallId,
+			DoAsEnsureOriginOf<T>,
+		),
 	}
 
 	/// Old name generated by `decl_event`.
@@ -358,10 +856,16 @@ pub mod pallet {
 		/// Account is not a member
 		NotMember,
 		/// Duplicate proposals not allowed
+		///
+		/// No longer returned since motions are keyed by index, kept so the other errors keep
+		/// their indices.
 		DuplicateProposal,
 		/// Proposal must exist
 		ProposalMissing,
 		/// Mismatched index
+		///
+		/// No longer returned since motions are keyed by index, kept so the other errors keep
+		/// their indices.
 		WrongIndex,
 		/// Duplicate vote ignored
 		DuplicateVote,
@@ -382,16 +886,66 @@ pub mod pallet {
 		/// Threshold exceeds the number of people
 		ThresholdWrong,
 		ThresholdTooLow,
+		/// The title of the motion is too long.
+		TitleTooLong,
+		/// The description hash of the motion is too long.
+		DescriptionHashTooLong,
+		/// Account is not the veto holder.
+		NotVetoHolder,
+		/// Only an approved motion that is cooling off can be vetoed.
+		NotCoolingOff,
+		/// The motion was vetoed and can not be proposed yet.
+		ProposalBlacklisted,
+		/// Account is not the proposer of the motion.
+		NotProposer,
+		/// A member can not delegate to themselves.
+		SelfDelegation,
+		/// Delegations can not be chained.
+		DelegationChain,
+		/// The member has not delegated their vote.
+		NotDelegating,
+		/// The committee does not exist.
+		CommitteeMissing,
+		/// The name of the committee is too long.
+		CommitteeNameTooLong,
+		/// The composed origin is too deep.
+		OriginTooDeep,
+		/// There are more than `MAX_MAGNITUDE_TIERS` tiers.
+		TooManyTiers,
+		/// The tiers are not in strictly ascending order of magnitude.
+		TiersNotAscending,
+		/// There are more than `MAX_PRESET_CALLS` calls.
+		TooManyCalls,
+		/// The name of the preset is too long.
+		PresetNameTooLong,
+		/// The preset does not exist.
+		PresetMissing,
+		/// The period of an allowance can not be zero.
+		AllowancePeriodZero,
+		/// The member has used up their executions in this period.
+		AllowanceExhausted,
+		/// The call would exceed the value the member can execute in this period.
+		AllowanceValueExceeded,
+		/// A motion approved by just the threshold can not meet the origin of the call it wraps.
+		ThresholdCannotMeetOrigin,
+		/// There are more than `MAX_SIGNED_VOTES` signed votes.
+		TooManyVotes,
+		/// The signature of a signed vote is invalid.
+		BadSignature,
 	}
 
 	// Note that councillor operations are assigned to the operational class.
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Dispatch a proposal from a member using the `Member` origin.
+		///
+		/// Counts against the allowance of the member if they have one, whether or not the
+		/// proposal succeeds.
 		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::execute())]
 		pub fn execute(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
+			committee_id: CommitteeId,
 			proposal: Box<<T as Config<I>>::Proposal>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
@@ -401,9 +955,14 @@ pub mod pallet {
 					dao::Error::<T>::InVailCall
 				);
 			}
-			ensure!(Self::is_member(dao_id, &who)?, Error::<T, I>::NotMember);
+			Self::ensure_committee(dao_id, committee_id)?;
+			ensure!(
+				Self::is_committee_member(dao_id, committee_id, &who),
+				Error::<T, I>::NotMember
+			);
+			Self::use_allowance(dao_id, &who, &proposal)?;
 			let proposal_hash = T::Hashing::hash_of(&proposal);
-			let result = proposal.dispatch(RawOrigin::Member(dao_id).into());
+			let result = proposal.dispatch(RawOrigin::Member(dao_id, committee_id).into());
 			Self::deposit_event(Event::MemberExecuted {
 				proposal_hash,
 				result: result.map(|_| ()).map_err(|e| e.error),
@@ -412,15 +971,31 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Add a new proposal to either be voted on or executed directly.
+		/// Add a new proposal to be voted on by a committee.
+		///
+		/// The same call can be proposed again while it is still active, as a separate motion.
+		///
+		/// The threshold can not be lower than the one of the committee. If the proposal is a
+		/// `do_as_agency` call, the threshold must also meet the origin of the call it wraps.
+		///
+		/// The call must be noted in the preimage store of the DAO. It is decoded here for these
+		/// checks, but only the preimage is stored with the motion.
+		///
+		/// `title` and `description_hash` (an IPFS CID) tell voters why the motion exists.
 		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::propose())]
 		pub fn propose(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
+			committee_id: CommitteeId,
 			#[pallet::compact] threshold: MemberCount,
-			proposal: Box<<T as Config<I>>::Proposal>,
+			preimage: PreimageRef<T::Hash>,
+			title: Option<Vec<u8>>,
+			description_hash: Option<Vec<u8>>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			let metadata = Self::try_bound_metadata(title, description_hash)?;
+			let proposal: <T as Config<I>>::Proposal =
+				dao::Pallet::<T>::try_get_preimage_call(dao_id, &preimage)?;
 			if !cfg!(any(feature = "std", feature = "runtime-benchmarks")) {
 				ensure!(
 					T::CollectiveBaseCallFilter::contains(&proposal),
@@ -428,19 +1003,32 @@ pub mod pallet {
 				);
 			}
 			ensure!(threshold > 1 as MemberCount, Error::<T, I>::ThresholdTooLow);
-			ensure!(Self::is_member(dao_id, &who)?, Error::<T, I>::NotMember);
-			let proposal_hash = T::Hashing::hash_of(&proposal);
+			if committee_id != DEFAULT_COMMITTEE {
+				let info =
+					Self::committees(dao_id, committee_id).ok_or(Error::<T, I>::CommitteeMissing)?;
+				ensure!(threshold >= info.threshold, Error::<T, I>::ThresholdTooLow);
+			}
 			ensure!(
-				!<ProposalOf<T, I>>::contains_key(&dao_id, proposal_hash),
-				Error::<T, I>::DuplicateProposal
+				Self::is_committee_member(dao_id, committee_id, &who),
+				Error::<T, I>::NotMember
 			);
+			let proposal_hash = preimage.hash;
+			if let Some(until) = Blacklist::<T, I>::get(dao_id, proposal_hash) {
+				ensure!(Self::now() >= until, Error::<T, I>::ProposalBlacklisted);
+				Blacklist::<T, I>::remove(dao_id, proposal_hash);
+			}
 
 			ensure!(
-				Self::collective_members(dao_id).len() as u32 >= threshold,
+				Self::members_of(dao_id, committee_id).len() as u32 >= threshold,
 				Error::<T, I>::ThresholdWrong
 			);
+			ensure!(
+				Self::threshold_meets_origin(dao_id, committee_id, threshold, &proposal),
+				Error::<T, I>::ThresholdCannotMeetOrigin
+			);
+			let index = Self::proposal_count(dao_id);
 			<Proposals<T, I>>::try_mutate(dao_id, |proposals| -> DispatchResult {
-				proposals.push(proposal_hash);
+				proposals.push(index);
 				ensure!(
 					proposals.len() as u32 <= MaxProposals::<T, I>::get(dao_id),
 					Error::<T, I>::WrongProposalLength
@@ -448,22 +1036,31 @@ pub mod pallet {
 				Ok(())
 			})?;
 
-			let index = Self::proposal_count(dao_id);
 			<ProposalCount<T, I>>::mutate(dao_id, |i| *i += 1);
-			<ProposalOf<T, I>>::insert(dao_id, proposal_hash, *proposal);
-			let votes = {
-				let end =
-					frame_system::Pallet::<T>::block_number() + MotionDuration::<T, I>::get(dao_id).into();
-				// fixme
-				Votes { index, threshold, ayes: vec![who.clone()], nays: vec![], end: 100 }
+			dao::Pallet::<T>::request_preimage(dao_id, &preimage)?;
+			<ProposalOf<T, I>>::insert(dao_id, index, preimage);
+			<ProposalIndexes<T, I>>::append(dao_id, proposal_hash, index);
+			let end = Self::now().saturating_add(MotionDuration::<T, I>::get(dao_id));
+			let votes = Votes {
+				index,
+				threshold,
+				ayes: vec![who.clone()],
+				nays: vec![],
+				abstentions: vec![],
+				end,
+				committee: committee_id,
 			};
-			<Voting<T, I>>::insert(dao_id, proposal_hash, votes);
+			<Voting<T, I>>::insert(dao_id, index, votes);
+			<MotionsDue<T, I>>::append(end, (dao_id, index));
+			<MetadataOf<T, I>>::insert(dao_id, index, metadata.clone());
+			<ProposerOf<T, I>>::insert(dao_id, index, who.clone());
 
 			Self::deposit_event(Event::Proposed {
 				account: who,
 				proposal_index: index,
 				proposal_hash,
 				threshold,
+				metadata,
 			});
 
 			Ok(().into())
@@ -474,51 +1071,77 @@ pub mod pallet {
 		pub fn vote(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
-			proposal: T::Hash,
 			#[pallet::compact] index: ProposalIndex,
 			approve: bool,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_member(dao_id, &who)?, Error::<T, I>::NotMember);
-
-			let mut voting =
-				Self::voting(dao_id, &proposal).ok_or(Error::<T, I>::ProposalMissing)?;
-			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+			Self::do_vote(dao_id, index, who, approve)?;
 
-			let position_yes = voting.ayes.iter().position(|a| a == &who);
-			let position_no = voting.nays.iter().position(|a| a == &who);
+			Ok(().into())
+		}
 
-			if approve {
-				if position_yes.is_none() {
-					voting.ayes.push(who.clone());
-				} else {
-					return Err(Error::<T, I>::DuplicateVote.into())
-				}
-				if let Some(pos) = position_no {
-					voting.nays.swap_remove(pos);
-				}
-			} else {
-				if position_no.is_none() {
-					voting.nays.push(who.clone());
-				} else {
-					return Err(Error::<T, I>::DuplicateVote.into())
-				}
-				if let Some(pos) = position_yes {
-					voting.ayes.swap_remove(pos);
-				}
+		/// Record a batch of votes that members signed off-chain.
+		///
+		/// Each signature is over `signed_vote_payload` of its vote, which binds the DAO, the
+		/// chain and the nonce of the member, so a signed vote can be submitted only once.
+		/// Anyone can submit the batch and pay its fee.
+		#[pallet::weight(
+			<T as pallet::Config<I>>::WeightInfo::submit_signed_votes()
+				.saturating_mul(votes.len() as u64)
+		)]
+		pub fn submit_signed_votes(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			votes: Vec<SignedVoteOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(votes.len() <= MAX_SIGNED_VOTES as usize, Error::<T, I>::TooManyVotes);
+
+			for (who, index, approve, signature) in votes {
+				let payload = Self::signed_vote_payload(dao_id, &who, index, approve);
+				ensure!(signature.verify(&payload[..], &who), Error::<T, I>::BadSignature);
+				SignedVoteNonce::<T, I>::mutate(dao_id, &who, |nonce| {
+					*nonce = nonce.saturating_add(1)
+				});
+				Self::do_vote(dao_id, index, who, approve)?;
 			}
 
-			let yes_votes = voting.ayes.len() as MemberCount;
-			let no_votes = voting.nays.len() as MemberCount;
-			Self::deposit_event(Event::Voted {
+			Ok(().into())
+		}
+
+		/// Record an explicit abstention for the sender on the given proposal.
+		///
+		/// An abstention counts toward turnout but toward neither side, and is never converted
+		/// by the prime or the default vote.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::abstain())]
+		pub fn abstain(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			#[pallet::compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut voting = Self::voting(dao_id, index).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(
+				Self::is_committee_member(dao_id, voting.committee, &who),
+				Error::<T, I>::NotMember
+			);
+			ensure!(!voting.abstentions.contains(&who), Error::<T, I>::DuplicateVote);
+
+			voting.ayes.retain(|a| a != &who);
+			voting.nays.retain(|a| a != &who);
+			voting.abstentions.push(who.clone());
+
+			let (yes, no, abstain) = Self::tally(dao_id, &voting);
+			Self::deposit_event(Event::Abstained {
 				account: who,
-				proposal_hash: proposal,
-				voted: approve,
-				yes: yes_votes,
-				no: no_votes,
+				proposal_index: index,
+				yes,
+				no,
+				abstain,
 			});
 
-			Voting::<T, I>::insert(dao_id, &proposal, voting);
+			Voting::<T, I>::insert(dao_id, index, voting);
 
 			Ok(().into())
 		}
@@ -528,77 +1151,80 @@ pub mod pallet {
 		pub fn close(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
-			proposal_hash: T::Hash,
 			#[pallet::compact] index: ProposalIndex,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 
-			let voting =
-				Self::voting(dao_id, &proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
-			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
-
-			let mut no_votes = voting.nays.len() as MemberCount;
-			let mut yes_votes = voting.ayes.len() as MemberCount;
-			let seats = Self::collective_members(dao_id).len() as MemberCount;
-			let approved = yes_votes >= voting.threshold;
-			let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
-			// Allow (dis-)approving the proposal as soon as there are enough votes.
-			if approved {
-				let proposal = Self::validate_and_get_proposal(&proposal_hash, dao_id)?;
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let _ =
-					Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal, dao_id);
-				return Ok(().into())
-			} else if disapproved {
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let _proposal_count = Self::do_disapprove_proposal(proposal_hash, dao_id);
-				return Ok(().into())
-			}
-
-			// Only allow actual closing of the proposal after the voting period has ended.
-			// fixme
-			// ensure!(
-			// 	frame_system::Pallet::<T>::block_number() >= voting.end,
-			// 	Error::<T, I>::TooEarly
-			// );
-
-			let prime_vote = Self::prime(dao_id).map(|who| voting.ayes.iter().any(|a| a == &who));
-
-			// default voting strategy.
-			let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
-
-			let abstentions = seats - (yes_votes + no_votes);
-			match default {
-				true => yes_votes += abstentions,
-				false => no_votes += abstentions,
-			}
-			let approved = yes_votes >= voting.threshold;
-
-			if approved {
-				let proposal = Self::validate_and_get_proposal(&proposal_hash, dao_id)?;
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let _ =
-					Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal, dao_id);
-				Ok(().into())
-			} else {
-				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
-				let _proposal_count = Self::do_disapprove_proposal(proposal_hash, dao_id);
-				Ok(().into())
-			}
+			let voting = Self::voting(dao_id, index).ok_or(Error::<T, I>::ProposalMissing)?;
+			Self::do_close(dao_id, index, voting)?;
+			Ok(().into())
 		}
 
-		/// call id:201
+		/// Delegate the sender's vote to another member.
 		///
-		/// Disapprove a proposal, close, and remove it from the system, regardless of its current state.
-		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::disapprove_proposal())]
-		pub fn disapprove_proposal(
+		/// Until revoked, the delegate's vote also counts for the sender in motions of the default
+		/// committee whenever the sender does not vote themselves.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::delegate())]
+		pub fn delegate(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
-			proposal_hash: T::Hash,
+			to: T::AccountId,
 		) -> DispatchResultWithPostInfo {
-			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
-			let _proposal_count = Self::do_disapprove_proposal(proposal_hash, dao_id);
-			Ok(().into())
+			let who = ensure_signed(origin)?;
+			ensure!(who != to, Error::<T, I>::SelfDelegation);
+			ensure!(Self::is_member(dao_id, &who)?, Error::<T, I>::NotMember);
+			ensure!(Self::is_member(dao_id, &to)?, Error::<T, I>::NotMember);
+			ensure!(
+				!Delegations::<T, I>::contains_key(dao_id, &to) &&
+					!Delegations::<T, I>::iter_prefix_values(dao_id).any(|d| d == who),
+				Error::<T, I>::DelegationChain
+			);
+			Delegations::<T, I>::insert(dao_id, &who, &to);
+			Self::deposit_event(Event::Delegated { dao_id, who, to });
+			Ok(().into())
+		}
+
+		/// Revoke the sender's delegation.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, dao_id: T::DaoId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Delegations::<T, I>::take(dao_id, &who).is_some(), Error::<T, I>::NotDelegating);
+			Self::deposit_event(Event::Undelegated { dao_id, who });
+			Ok(().into())
+		}
+
+		/// The proposer takes back a motion, removing it from the system.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::withdraw())]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			#[pallet::compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let proposer =
+				Self::proposer_of(dao_id, index).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(proposer == who, Error::<T, I>::NotProposer);
+			let _proposal_count = Self::remove_proposal(index, dao_id);
+			Self::deposit_event(Event::Withdrawn { proposal_index: index });
+			Ok(().into())
+		}
+
+		/// call id:201
+		///
+		/// Disapprove a proposal, close, and remove it from the system, regardless of its current state.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::disapprove_proposal())]
+		pub fn disapprove_proposal(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			#[pallet::compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			ensure!(
+				ProposalOf::<T, I>::contains_key(dao_id, index),
+				Error::<T, I>::ProposalMissing
+			);
+			let _proposal_count = Self::do_disapprove_proposal(index, dao_id);
+			Ok(().into())
 		}
 
 		/// call id:202
@@ -654,25 +1280,321 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
 			call_id: T::CallId,
-			ensure: DoAsEnsureOrigin<Proportion<MemberCount>, MemberCount>,
+			ensure: DoAsEnsureOriginOf<T>,
 		) -> DispatchResultWithPostInfo {
 			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
-
-			if let DoAsEnsureOrigin::Proportion(x) = ensure.clone() {
-				match x {
-					Proportion::MoreThan(n, m) => {
-						ensure!(n <= m, Error::<T, I>::ProportionErr);
-					},
-					Proportion::AtLeast(n, m) => {
-						ensure!(n <= m, Error::<T, I>::ProportionErr);
-					},
-				}
-			}
+			Self::check_ensure_origin(&ensure)?;
 
 			EnsureOrigins::<T, I>::insert(dao_id, call_id, ensure.clone());
 			Self::deposit_event(Event::SetOrigin(dao_id, call_id, ensure));
 			Ok(().into())
 		}
+
+		/// Veto an approved motion that is still cooling off.
+		///
+		/// The motion is removed and can not be proposed again for `BlacklistPeriod` blocks.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::veto())]
+		pub fn veto(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			#[pallet::compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::veto_holder(dao_id) == Some(who.clone()), Error::<T, I>::NotVetoHolder);
+			ensure!(Self::is_member(dao_id, &who)?, Error::<T, I>::NotMember);
+			ensure!(CoolingOff::<T, I>::contains_key(dao_id, index), Error::<T, I>::NotCoolingOff);
+
+			let proposal_hash =
+				ProposalOf::<T, I>::get(dao_id, index).ok_or(Error::<T, I>::ProposalMissing)?.hash;
+			let until = Self::now().saturating_add(BlacklistPeriod::<T, I>::get(dao_id));
+			Blacklist::<T, I>::insert(dao_id, proposal_hash, until);
+			let _proposal_count = Self::remove_proposal(index, dao_id);
+			Self::deposit_event(Event::Vetoed {
+				dao_id,
+				who,
+				proposal_index: index,
+				proposal_hash,
+				until,
+			});
+			Ok(().into())
+		}
+
+		/// call id:206
+		///
+		/// Set the member who can veto approved motions, or remove it.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_veto_holder())]
+		pub fn set_veto_holder(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			who: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			match who.clone() {
+				Some(holder) => {
+					ensure!(Self::is_member(dao_id, &holder)?, Error::<T, I>::NotMember);
+					VetoHolder::<T, I>::insert(dao_id, holder);
+				},
+				None => VetoHolder::<T, I>::remove(dao_id),
+			}
+			Self::deposit_event(Event::SetVetoHolder { dao_id, who });
+			Ok(().into())
+		}
+
+		/// call id:207
+		///
+		/// Set the length of time an approved motion waits before it can be executed.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_cooling_off_period())]
+		pub fn set_cooling_off_period(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			period: u32,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			CoolingOffPeriod::<T, I>::insert(dao_id, period);
+			Self::deposit_event(Event::SetCoolingOffPeriod { dao_id, period });
+			Ok(().into())
+		}
+
+		/// call id:208
+		///
+		/// Set the length of time a vetoed motion can not be proposed again.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_blacklist_period())]
+		pub fn set_blacklist_period(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			period: u32,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			BlacklistPeriod::<T, I>::insert(dao_id, period);
+			Self::deposit_event(Event::SetBlacklistPeriod { dao_id, period });
+			Ok(().into())
+		}
+
+		/// call id:209
+		///
+		/// Create a named committee, whose motions must require at least `threshold` approvals.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::create_committee())]
+		pub fn create_committee(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			name: Vec<u8>,
+			threshold: MemberCount,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			let name: BoundedVec<u8, T::MaxCommitteeNameLength> =
+				name.try_into().map_err(|_| Error::<T, I>::CommitteeNameTooLong)?;
+			let committee_id = Self::next_committee_id(dao_id);
+			NextCommitteeId::<T, I>::insert(dao_id, committee_id.saturating_add(1));
+			Committees::<T, I>::insert(
+				dao_id,
+				committee_id,
+				CommitteeInfo { name: name.clone(), threshold },
+			);
+			Self::deposit_event(Event::CommitteeCreated { dao_id, committee_id, name });
+			Ok(().into())
+		}
+
+		/// call id:210
+		///
+		/// Set the members of a committee.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_committee_members())]
+		pub fn set_committee_members(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			members: Vec<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			Self::ensure_committee(dao_id, committee_id)?;
+			Self::do_set_members(dao_id, committee_id, &members, None)?;
+			Self::deposit_event(Event::SetCommitteeMembers {
+				dao_id,
+				committee_id,
+				members: Self::members_of(dao_id, committee_id),
+			});
+			Ok(().into())
+		}
+
+		/// call id:211
+		///
+		/// Set the least number of approvals the motions of a named committee must require.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_committee_threshold())]
+		pub fn set_committee_threshold(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			threshold: MemberCount,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			Committees::<T, I>::try_mutate(dao_id, committee_id, |info| -> DispatchResult {
+				let info = info.as_mut().ok_or(Error::<T, I>::CommitteeMissing)?;
+				info.threshold = threshold;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::SetCommitteeThreshold { dao_id, committee_id, threshold });
+			Ok(().into())
+		}
+
+		/// call id:212
+		///
+		/// Set origin for a specific call of a committee.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_committee_ensure_origin())]
+		pub fn set_committee_ensure_origin(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			call_id: T::CallId,
+			ensure: DoAsEnsureOriginOf<T>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			Self::ensure_committee(dao_id, committee_id)?;
+			Self::check_ensure_origin(&ensure)?;
+
+			if committee_id == DEFAULT_COMMITTEE {
+				EnsureOrigins::<T, I>::insert(dao_id, call_id, ensure.clone());
+			} else {
+				CommitteeEnsureOrigins::<T, I>::insert(
+					(dao_id, committee_id, call_id),
+					ensure.clone(),
+				);
+			}
+			Self::deposit_event(Event::SetCommitteeOrigin(dao_id, committee_id, call_id, ensure));
+			Ok(().into())
+		}
+
+		/// call id:213
+		///
+		/// Set how the members who did not vote are counted when a motion is closed.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_default_vote_policy())]
+		pub fn set_default_vote_policy(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			policy: DefaultVotePolicy,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			DefaultVoteOf::<T, I>::insert(dao_id, policy);
+			Self::deposit_event(Event::SetDefaultVotePolicy { dao_id, policy });
+			Ok(().into())
+		}
+
+		/// call id:214
+		///
+		/// Set the origins a call of a committee needs from a given magnitude on, such as the
+		/// amount of a spend.
+		///
+		/// `tiers` must be in strictly ascending order of magnitude. An empty list removes them.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_ensure_origin_tiers())]
+		pub fn set_ensure_origin_tiers(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			committee_id: CommitteeId,
+			call_id: T::CallId,
+			tiers: Vec<(u128, DoAsEnsureOriginOf<T>)>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			Self::ensure_committee(dao_id, committee_id)?;
+			ensure!(
+				tiers.windows(2).all(|w| w[0].0 < w[1].0),
+				Error::<T, I>::TiersNotAscending
+			);
+			for (_, ensure) in tiers.iter() {
+				Self::check_ensure_origin(ensure)?;
+			}
+			let tiers: EnsureOriginTiersOf<T> =
+				tiers.try_into().map_err(|_| Error::<T, I>::TooManyTiers)?;
+
+			if tiers.is_empty() {
+				EnsureOriginTiers::<T, I>::remove((dao_id, committee_id, call_id));
+			} else {
+				EnsureOriginTiers::<T, I>::insert((dao_id, committee_id, call_id), tiers.clone());
+			}
+			Self::deposit_event(Event::SetOriginTiers { dao_id, committee_id, call_id, tiers });
+			Ok(().into())
+		}
+
+		/// call id:215
+		///
+		/// Set origins for some calls at once.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_ensure_origins())]
+		pub fn set_ensure_origins(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			origins: Vec<(T::CallId, DoAsEnsureOriginOf<T>)>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			let origins = Self::check_ensure_origins(origins)?;
+
+			Self::do_set_ensure_origins(dao_id, origins);
+			Ok(().into())
+		}
+
+		/// Register origins for some calls under a name, such as `conservative` or `fast`.
+		///
+		/// The template does this with the root origin. An empty list removes the preset.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::register_ensure_origin_preset())]
+		pub fn register_ensure_origin_preset(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			origins: Vec<(T::CallId, DoAsEnsureOriginOf<T>)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let name: PresetName =
+				name.try_into().map_err(|_| Error::<T, I>::PresetNameTooLong)?;
+			let origins = Self::check_ensure_origins(origins)?;
+
+			if origins.is_empty() {
+				EnsureOriginPresets::<T, I>::remove(&name);
+			} else {
+				EnsureOriginPresets::<T, I>::insert(&name, origins.clone());
+			}
+			Self::deposit_event(Event::OriginPresetRegistered { name, origins });
+			Ok(().into())
+		}
+
+		/// call id:216
+		///
+		/// Set the origins of a preset the template registered.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::apply_ensure_origin_preset())]
+		pub fn apply_ensure_origin_preset(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			let name: PresetName =
+				name.try_into().map_err(|_| Error::<T, I>::PresetNameTooLong)?;
+			let origins =
+				EnsureOriginPresets::<T, I>::get(&name).ok_or(Error::<T, I>::PresetMissing)?;
+
+			Self::do_set_ensure_origins(dao_id, origins);
+			Self::deposit_event(Event::OriginPresetApplied { dao_id, name });
+			Ok(().into())
+		}
+
+		/// call id:217
+		///
+		/// Set how much a member can execute alone in each period, or remove the limit.
+		///
+		/// The usage of the member starts over.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_allowance())]
+		pub fn set_allowance(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			who: T::AccountId,
+			allowance: Option<Allowance>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			match allowance {
+				Some(a) => {
+					ensure!(a.period > 0, Error::<T, I>::AllowancePeriodZero);
+					Allowances::<T, I>::insert(dao_id, &who, a);
+				},
+				None => Allowances::<T, I>::remove(dao_id, &who),
+			}
+			AllowanceUsageOf::<T, I>::remove(dao_id, &who);
+			Self::deposit_event(Event::SetAllowance { dao_id, who, allowance });
+			Ok(().into())
+		}
 	}
 }
 
@@ -695,67 +1617,530 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(members.contains(who))
 	}
 
-	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
+	/// All members of a committee.
+	pub fn members_of(dao_id: T::DaoId, committee_id: CommitteeId) -> Vec<T::AccountId> {
+		if committee_id == DEFAULT_COMMITTEE {
+			Self::collective_members(dao_id)
+		} else {
+			Self::committee_members(dao_id, committee_id)
+		}
+	}
+
+	/// Check whether `who` is a member of a committee.
+	pub fn is_committee_member(
+		dao_id: T::DaoId,
+		committee_id: CommitteeId,
+		who: &T::AccountId,
+	) -> bool {
+		Self::members_of(dao_id, committee_id).contains(who)
+	}
+
+	/// Add an aye or nay vote of `who` to the given proposal.
+	fn do_vote(
+		dao_id: T::DaoId,
+		index: ProposalIndex,
+		who: T::AccountId,
+		approve: bool,
+	) -> DispatchResult {
+		let mut voting = Self::voting(dao_id, index).ok_or(Error::<T, I>::ProposalMissing)?;
+		ensure!(
+			Self::is_committee_member(dao_id, voting.committee, &who),
+			Error::<T, I>::NotMember
+		);
+
+		let position_yes = voting.ayes.iter().position(|a| a == &who);
+		let position_no = voting.nays.iter().position(|a| a == &who);
+
+		if approve {
+			if position_yes.is_none() {
+				voting.ayes.push(who.clone());
+			} else {
+				return Err(Error::<T, I>::DuplicateVote.into())
+			}
+			if let Some(pos) = position_no {
+				voting.nays.swap_remove(pos);
+			}
+		} else {
+			if position_no.is_none() {
+				voting.nays.push(who.clone());
+			} else {
+				return Err(Error::<T, I>::DuplicateVote.into())
+			}
+			if let Some(pos) = position_yes {
+				voting.ayes.swap_remove(pos);
+			}
+		}
+		voting.abstentions.retain(|a| a != &who);
+
+		let (yes_votes, no_votes, _) = Self::tally(dao_id, &voting);
+		Self::deposit_event(Event::Voted {
+			account: who,
+			proposal_index: index,
+			voted: approve,
+			yes: yes_votes,
+			no: no_votes,
+		});
+
+		Voting::<T, I>::insert(dao_id, index, voting);
+		Ok(())
+	}
+
+	/// The payload a member signs off-chain to vote on a motion.
+	pub fn signed_vote_payload(
+		dao_id: T::DaoId,
+		who: &T::AccountId,
+		index: ProposalIndex,
+		approve: bool,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(
+			SIGNED_VOTE_CONTEXT,
+			<Self as PalletInfoAccess>::index() as u32,
+			dao_id,
+			genesis_hash,
+			index,
+			approve,
+			Self::signed_vote_nonce(dao_id, who),
+		)
+			.encode()
+	}
+
+	/// Count an execution of a member against their allowance, if they have one.
 	///
-	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
-	/// to the length.
+	/// The periods start at the multiples of the period length.
+	fn use_allowance(
+		dao_id: T::DaoId,
+		who: &T::AccountId,
+		proposal: &<T as Config<I>>::Proposal,
+	) -> DispatchResult {
+		let allowance = match Allowances::<T, I>::get(dao_id, who) {
+			Some(allowance) => allowance,
+			None => return Ok(()),
+		};
+		let now = Self::now();
+		let period_start = now - now % allowance.period;
+		let mut usage = AllowanceUsageOf::<T, I>::get(dao_id, who);
+		if usage.period_start != period_start {
+			usage = AllowanceUsage { period_start, ..Default::default() };
+		}
+
+		ensure!(usage.executions < allowance.max_executions, Error::<T, I>::AllowanceExhausted);
+		usage.executions += 1;
+		if let Some(max_value) = allowance.max_value {
+			// the value of a `do_as_agency` call is the one of the call it wraps.
+			let call: &<T as dao::Config>::Call = match proposal.is_sub_type() {
+				Some(daos_doas::Call::do_as_agency { call, .. }) => call,
+				_ => <<T as dao::Config>::Call as IsType<_>>::from_ref(
+					<<T as Config<I>>::Proposal as IsType<_>>::into_ref(proposal),
+				),
+			};
+			let value = usage.value.saturating_add(
+				<T as dao::Config>::CallMagnitude::magnitude(call).unwrap_or_default(),
+			);
+			ensure!(value <= max_value, Error::<T, I>::AllowanceValueExceeded);
+			usage.value = value;
+		}
+		AllowanceUsageOf::<T, I>::insert(dao_id, who, usage);
+		Ok(())
+	}
+
+	/// Check some origins and bound them for `set_ensure_origins` and presets.
+	fn check_ensure_origins(
+		origins: Vec<(T::CallId, DoAsEnsureOriginOf<T>)>,
+	) -> Result<EnsureOriginsOf<T>, DispatchError> {
+		for (_, ensure) in origins.iter() {
+			Self::check_ensure_origin(ensure)?;
+		}
+		Ok(origins.try_into().map_err(|_| Error::<T, I>::TooManyCalls)?)
+	}
+
+	fn do_set_ensure_origins(dao_id: T::DaoId, origins: EnsureOriginsOf<T>) {
+		for (call_id, ensure) in origins {
+			EnsureOrigins::<T, I>::insert(dao_id, call_id, ensure.clone());
+			Self::deposit_event(Event::SetOrigin(dao_id, call_id, ensure));
+		}
+	}
+
+	/// The origin a committee needs for a call of the given magnitude.
+	///
+	/// The highest tier the magnitude reaches applies, otherwise the origin of the call.
+	pub fn ensure_origin_of(
+		dao_id: T::DaoId,
+		committee_id: CommitteeId,
+		call_id: T::CallId,
+		magnitude: Option<u128>,
+	) -> DoAsEnsureOriginOf<T> {
+		if let Some(magnitude) = magnitude {
+			let tier = Self::ensure_origin_tiers((dao_id, committee_id, call_id))
+				.into_iter()
+				.rev()
+				.find(|(from, _)| magnitude >= *from);
+			if let Some((_, ensure)) = tier {
+				return ensure
+			}
+		}
+		if committee_id == DEFAULT_COMMITTEE {
+			Self::ensures(dao_id, call_id)
+		} else {
+			Self::committee_ensures((dao_id, committee_id, call_id))
+		}
+	}
+
+	fn ensure_committee(dao_id: T::DaoId, committee_id: CommitteeId) -> DispatchResult {
+		ensure!(
+			committee_id == DEFAULT_COMMITTEE ||
+				Committees::<T, I>::contains_key(dao_id, committee_id),
+			Error::<T, I>::CommitteeMissing
+		);
+		Ok(())
+	}
+
+	fn check_ensure_origin(ensure: &DoAsEnsureOriginOf<T>) -> DispatchResult {
+		ensure!(ensure.depth() <= MAX_ENSURE_ORIGIN_DEPTH, Error::<T, I>::OriginTooDeep);
+		match ensure {
+			DoAsEnsureOrigin::Proportion(
+				Proportion::MoreThan(n, m) | Proportion::AtLeast(n, m),
+			) => ensure!(n <= m, Error::<T, I>::ProportionErr),
+			DoAsEnsureOrigin::And(a, b) | DoAsEnsureOrigin::Or(a, b) => {
+				Self::check_ensure_origin(a)?;
+				Self::check_ensure_origin(b)?;
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	/// Whether a motion approved by just `threshold` members can dispatch the call it wraps in
+	/// `do_as_agency`.
+	///
+	/// Proposals that are not `do_as_agency` calls always can.
+	fn threshold_meets_origin(
+		dao_id: T::DaoId,
+		committee_id: CommitteeId,
+		threshold: MemberCount,
+		proposal: &<T as Config<I>>::Proposal,
+	) -> bool {
+		let (call_dao_id, call_committee_id, call) = match proposal.is_sub_type() {
+			Some(daos_doas::Call::do_as_agency { dao_id, committee_id, call }) =>
+				(*dao_id, *committee_id, call),
+			_ => return true,
+		};
+		let call_id: T::CallId =
+			TryFrom::<<T as dao::Config>::Call>::try_from(*call.clone()).unwrap_or_default();
+		let magnitude = <T as dao::Config>::CallMagnitude::magnitude(call);
+		let ensure = Self::ensure_origin_of(call_dao_id, call_committee_id, call_id, magnitude);
+		let seats = Self::members_of(dao_id, committee_id).len() as MemberCount;
+		let raw = RawOrigin::Members(dao_id, committee_id, threshold, seats);
+		Self::origin_meets(call_dao_id, call_committee_id, Some(&raw), None, &ensure)
+	}
+
+	/// Check whether an origin of the agency or a signed account meets the origin a call needs.
+	///
+	/// `dao_id` and `committee_id` are the DAO and the committee the call is done for.
+	fn origin_meets(
+		dao_id: T::DaoId,
+		committee_id: CommitteeId,
+		raw: Option<&RawOrigin<T::DaoId, I>>,
+		signed: Option<&T::AccountId>,
+		ensure: &DoAsEnsureOriginOf<T>,
+	) -> bool {
+		match (ensure, raw) {
+			(
+				DoAsEnsureOrigin::Proportion(Proportion::MoreThan(x, y)),
+				Some(RawOrigin::Members(d, c, n, m)),
+			) => *d == dao_id && *c == committee_id && n * y > x * m,
+			(
+				DoAsEnsureOrigin::Proportion(Proportion::AtLeast(x, y)),
+				Some(RawOrigin::Members(d, c, n, m)),
+			) => *d == dao_id && *c == committee_id && n * y >= x * m,
+			(DoAsEnsureOrigin::Member, Some(RawOrigin::Member(d, c))) =>
+				*d == dao_id && *c == committee_id,
+			(DoAsEnsureOrigin::Members(x), Some(RawOrigin::Members(d, c, n, _m))) =>
+				*d == dao_id && *c == committee_id && n >= x,
+			(DoAsEnsureOrigin::AnyOf(committees), Some(RawOrigin::Members(d, c, _n, _m))) =>
+				*d == dao_id && committees.contains(c),
+			(DoAsEnsureOrigin::Account(who), _) => signed == Some(who),
+			(DoAsEnsureOrigin::And(a, b), _) =>
+				Self::origin_meets(dao_id, committee_id, raw, signed, a) &&
+					Self::origin_meets(dao_id, committee_id, raw, signed, b),
+			(DoAsEnsureOrigin::Or(a, b), _) =>
+				Self::origin_meets(dao_id, committee_id, raw, signed, a) ||
+					Self::origin_meets(dao_id, committee_id, raw, signed, b),
+			_ => false,
+		}
+	}
+
+	/// Close a motion that is due, removing it if it can not be closed.
+	///
+	/// Returns the weight of the call the motion executed, if any.
+	fn close_due(dao_id: T::DaoId, index: ProposalIndex) -> Weight {
+		let voting = match Self::voting(dao_id, index) {
+			Some(voting) => voting,
+			None => return Weight::zero(),
+		};
+		match Self::do_close(dao_id, index, voting) {
+			Ok(weight) => weight,
+			// a motion that cools off is due again at the end of the cooling-off period.
+			Err(e) if e == Error::<T, I>::TooEarly.into() => Weight::zero(),
+			Err(e) => {
+				let _proposal_count = Self::do_disapprove_proposal(index, dao_id);
+				Self::deposit_event(Event::CloseFailed { dao_id, proposal_index: index, error: e });
+				Weight::zero()
+			},
+		}
+	}
+
+	/// The declared weight of the call of a motion, zero if it can not be read.
+	fn motion_weight(dao_id: T::DaoId, index: ProposalIndex) -> Weight {
+		Self::validate_and_get_proposal(index, dao_id)
+			.map(|proposal| proposal.get_dispatch_info().weight)
+			.unwrap_or_else(|_| Weight::zero())
+	}
+
+	/// Close a motion that is either approved, disapproved, cooled off or whose voting period
+	/// has ended.
+	///
+	/// Returns the weight of the call the motion executed, if any.
+	fn do_close(
+		dao_id: T::DaoId,
+		index: ProposalIndex,
+		voting: Votes<T::AccountId, u32>,
+	) -> Result<Weight, DispatchError> {
+		// An approved motion is executed once its cooling-off period is over.
+		if let Some((until, yes_votes, seats)) = Self::cooling_off(dao_id, index) {
+			ensure!(Self::now() >= until, Error::<T, I>::TooEarly);
+			let proposal = Self::validate_and_get_proposal(index, dao_id)?;
+			let (weight, _) = Self::do_execute_proposal(
+				seats,
+				yes_votes,
+				index,
+				proposal,
+				dao_id,
+				voting.committee,
+			);
+			return Ok(weight)
+		}
+
+		let (mut yes_votes, mut no_votes, abstain_votes) = Self::tally(dao_id, &voting);
+		let seats = Self::members_of(dao_id, voting.committee).len() as MemberCount;
+		let approved = yes_votes >= voting.threshold;
+		let disapproved =
+			seats.saturating_sub(no_votes.saturating_add(abstain_votes)) < voting.threshold;
+		// Allow (dis-)approving the proposal as soon as there are enough votes.
+		if approved {
+			let proposal = Self::validate_and_get_proposal(index, dao_id)?;
+			Self::deposit_event(Event::Closed {
+				proposal_index: index,
+				yes: yes_votes,
+				no: no_votes,
+			});
+			let (weight, _) = Self::do_approve_proposal(
+				seats,
+				yes_votes,
+				index,
+				proposal,
+				dao_id,
+				voting.committee,
+			);
+			return Ok(weight)
+		} else if disapproved {
+			Self::deposit_event(Event::Closed {
+				proposal_index: index,
+				yes: yes_votes,
+				no: no_votes,
+			});
+			let _proposal_count = Self::do_disapprove_proposal(index, dao_id);
+			return Ok(Weight::zero())
+		}
+
+		// Only allow actual closing of the proposal after the voting period has ended.
+		ensure!(Self::now() >= voting.end, Error::<T, I>::TooEarly);
+
+		// An abstaining prime gives no default, and named committees have no prime.
+		let prime = Self::prime(dao_id).filter(|_| voting.committee == DEFAULT_COMMITTEE);
+		let prime_vote = prime.and_then(|who| {
+			let voted = voting.ayes.contains(&who) ||
+				voting.nays.contains(&who) ||
+				voting.abstentions.contains(&who);
+			let voter =
+				if voted { who } else { Self::delegations(dao_id, &who).unwrap_or(who) };
+			(!voting.abstentions.contains(&voter)).then(|| voting.ayes.contains(&voter))
+		});
+
+		// default voting strategy of the DAO.
+		let policy = Self::default_vote_policy(dao_id);
+		let approved = if policy == DefaultVotePolicy::AbstainIgnored {
+			// the threshold applies to the share of the members who voted.
+			yes_votes > 0 &&
+				yes_votes.saturating_mul(seats) >=
+					voting.threshold.saturating_mul(yes_votes.saturating_add(no_votes))
+		} else {
+			let default = policy.default_vote(prime_vote, yes_votes, no_votes, seats);
+
+			// explicit abstentions are never converted.
+			let abstentions = seats.saturating_sub(yes_votes + no_votes + abstain_votes);
+			match default {
+				true => yes_votes += abstentions,
+				false => no_votes += abstentions,
+			}
+			yes_votes >= voting.threshold
+		};
+
+		if approved {
+			let proposal = Self::validate_and_get_proposal(index, dao_id)?;
+			Self::deposit_event(Event::Closed {
+				proposal_index: index,
+				yes: yes_votes,
+				no: no_votes,
+			});
+			let (weight, _) = Self::do_approve_proposal(
+				seats,
+				yes_votes,
+				index,
+				proposal,
+				dao_id,
+				voting.committee,
+			);
+			Ok(weight)
+		} else {
+			Self::deposit_event(Event::Closed {
+				proposal_index: index,
+				yes: yes_votes,
+				no: no_votes,
+			});
+			let _proposal_count = Self::do_disapprove_proposal(index, dao_id);
+			Ok(Weight::zero())
+		}
+	}
+
+	/// Count the yes, no and abstain votes of a motion.
+	///
+	/// A member who has not voted follows the vote of the member they delegated to. Delegations
+	/// are between members of the default committee, so they only count in its motions.
+	fn tally(
+		dao_id: T::DaoId,
+		voting: &Votes<T::AccountId, u32>,
+	) -> (MemberCount, MemberCount, MemberCount) {
+		let mut yes_votes = voting.ayes.len() as MemberCount;
+		let mut no_votes = voting.nays.len() as MemberCount;
+		let mut abstain_votes = voting.abstentions.len() as MemberCount;
+		if voting.committee != DEFAULT_COMMITTEE {
+			return (yes_votes, no_votes, abstain_votes)
+		}
+		let members = Self::members_of(dao_id, DEFAULT_COMMITTEE);
+		for (who, delegate) in Delegations::<T, I>::iter_prefix(dao_id) {
+			if !members.contains(&who) ||
+				voting.ayes.contains(&who) ||
+				voting.nays.contains(&who) ||
+				voting.abstentions.contains(&who)
+			{
+				continue
+			}
+			if voting.ayes.contains(&delegate) {
+				yes_votes += 1;
+			} else if voting.nays.contains(&delegate) {
+				no_votes += 1;
+			} else if voting.abstentions.contains(&delegate) {
+				abstain_votes += 1;
+			}
+		}
+		(yes_votes, no_votes, abstain_votes)
+	}
+
+	/// The title and description of a current motion, in the form used by the runtime api.
+	pub fn motion_metadata(
+		dao_id: T::DaoId,
+		index: ProposalIndex,
+	) -> Option<ProposalMetadata<Vec<u8>, Vec<u8>>> {
+		MetadataOf::<T, I>::get(dao_id, index).map(|m| ProposalMetadata {
+			title: m.title.map(Into::into),
+			description_hash: m.description_hash.map(Into::into),
+		})
+	}
+
+	fn now() -> u32 {
+		frame_system::Pallet::<T>::block_number().saturated_into::<u32>()
+	}
+
+	fn try_bound_metadata(
+		title: Option<Vec<u8>>,
+		description_hash: Option<Vec<u8>>,
+	) -> Result<BoundedMetadataOf<T, I>, DispatchError> {
+		let title = title
+			.map(|t| BoundedVec::try_from(t).map_err(|_| Error::<T, I>::TitleTooLong))
+			.transpose()?;
+		let description_hash = description_hash
+			.map(|d| BoundedVec::try_from(d).map_err(|_| Error::<T, I>::DescriptionHashTooLong))
+			.transpose()?;
+		Ok(ProposalMetadata { title, description_hash })
+	}
+
+	/// Get the preimage of a proposal from storage and decode the call it refers to.
 	fn validate_and_get_proposal(
-		hash: &T::Hash,
+		index: ProposalIndex,
 		dao_id: T::DaoId,
 	) -> Result<<T as Config<I>>::Proposal, DispatchError> {
-		let proposal =
-			ProposalOf::<T, I>::get(dao_id, hash).ok_or(Error::<T, I>::ProposalMissing)?;
-		Ok(proposal)
+		let preimage =
+			ProposalOf::<T, I>::get(dao_id, index).ok_or(Error::<T, I>::ProposalMissing)?;
+		dao::Pallet::<T>::try_get_preimage_call(dao_id, &preimage)
 	}
 
 	fn do_approve_proposal(
 		seats: MemberCount,
 		yes_votes: MemberCount,
-		proposal_hash: T::Hash,
+		index: ProposalIndex,
 		proposal: <T as Config<I>>::Proposal,
 		dao_id: T::DaoId,
+		committee_id: CommitteeId,
 	) -> (Weight, u32) {
-		Self::deposit_event(Event::Approved { proposal_hash });
+		Self::deposit_event(Event::Approved { proposal_index: index });
+
+		// With a veto holder around, the motion cools off before it can be executed.
+		if Self::veto_holder(dao_id).is_some() && Self::cooling_off_period(dao_id) > 0 {
+			let until = Self::now().saturating_add(Self::cooling_off_period(dao_id));
+			CoolingOff::<T, I>::insert(dao_id, index, (until, yes_votes, seats));
+			MotionsDue::<T, I>::append(until, (dao_id, index));
+			Self::deposit_event(Event::CoolingOff { proposal_index: index, until });
+			return (Weight::zero(), Self::proposals(dao_id).len() as u32)
+		}
 
+		Self::do_execute_proposal(seats, yes_votes, index, proposal, dao_id, committee_id)
+	}
+
+	fn do_execute_proposal(
+		seats: MemberCount,
+		yes_votes: MemberCount,
+		index: ProposalIndex,
+		proposal: <T as Config<I>>::Proposal,
+		dao_id: T::DaoId,
+		committee_id: CommitteeId,
+	) -> (Weight, u32) {
 		let dispatch_weight = proposal.get_dispatch_info().weight;
-		let origin =
-			RawOrigin::Members(<T as dao::Config>::DaoId::default(), yes_votes, seats).into();
+		let origin = RawOrigin::Members(dao_id, committee_id, yes_votes, seats).into();
 		let result = proposal.dispatch(origin);
 		Self::deposit_event(Event::Executed {
-			proposal_hash,
+			proposal_index: index,
 			result: result.map(|_| ()).map_err(|e| e.error),
 		});
 		// default to the dispatch info weight for safety
 		let proposal_weight = get_result_weight(result).unwrap_or(dispatch_weight); // P1
 
-		let proposal_count = Self::remove_proposal(proposal_hash, dao_id);
+		let proposal_count = Self::remove_proposal(index, dao_id);
 		(proposal_weight, proposal_count)
 	}
 
-	fn do_disapprove_proposal(proposal_hash: T::Hash, dao_id: T::DaoId) -> u32 {
+	fn do_disapprove_proposal(index: ProposalIndex, dao_id: T::DaoId) -> u32 {
 		// disapproved
-		Self::deposit_event(Event::Disapproved { proposal_hash });
-		Self::remove_proposal(proposal_hash, dao_id)
-	}
-
-	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
-	fn remove_proposal(proposal_hash: T::Hash, dao_id: T::DaoId) -> u32 {
-		// remove proposal and vote
-		ProposalOf::<T, I>::remove(dao_id, &proposal_hash);
-		Voting::<T, I>::remove(dao_id, &proposal_hash);
-		let num_proposals = Proposals::<T, I>::mutate(dao_id, |proposals| {
-			proposals.retain(|h| h != &proposal_hash);
-			proposals.len() + 1 // calculate weight based on original length
-		});
-		num_proposals as u32
+		Self::deposit_event(Event::Disapproved { proposal_index: index });
+		Self::remove_proposal(index, dao_id)
 	}
-}
 
-impl<T: Config<I>, I: 'static> SetCollectiveMembers<T::AccountId, T::DaoId, DispatchError>
-	for Pallet<T, I>
-{
-	fn set_members_sorted(
+	/// Set the members of a committee, removing the old ones from its current motions.
+	///
+	/// The prime and the delegations only belong to the default committee.
+	fn do_set_members(
 		dao_id: T::DaoId,
+		committee_id: CommitteeId,
 		members: &[T::AccountId],
 		prime: Option<T::AccountId>,
 	) -> Result<(), DispatchError> {
@@ -767,79 +2152,125 @@ impl<T: Config<I>, I: 'static> SetCollectiveMembers<T::AccountId, T::DaoId, Disp
 		// remove accounts from all current voting in motions.
 		let mut members = members.to_vec();
 		members.sort();
-		for h in Self::proposals(dao_id).into_iter() {
-			<Voting<T, I>>::mutate(dao_id, h, |v| {
-				if let Some(mut votes) = v.take() {
-					votes.ayes = votes
-						.ayes
-						.into_iter()
-						.filter(|i| members.binary_search(i).is_ok())
-						.collect();
-					votes.nays = votes
-						.nays
-						.into_iter()
-						.filter(|i| members.binary_search(i).is_ok())
-						.collect();
-					*v = Some(votes);
+		for index in Self::proposals(dao_id).into_iter() {
+			<Voting<T, I>>::mutate(dao_id, index, |v| {
+				if let Some(votes) = v.as_mut().filter(|votes| votes.committee == committee_id) {
+					votes.ayes.retain(|i| members.binary_search(i).is_ok());
+					votes.nays.retain(|i| members.binary_search(i).is_ok());
+					votes.abstentions.retain(|i| members.binary_search(i).is_ok());
 				}
 			});
 		}
-		if let Some(p) = prime {
-			Prime::<T, I>::insert(dao_id, p);
+		let old = Self::members_of(dao_id, committee_id);
+		let (incoming, outgoing) = traits::compute_members_diff_sorted(&members, &old);
+		if committee_id == DEFAULT_COMMITTEE {
+			// delegations only hold between members.
+			let stale = Delegations::<T, I>::iter_prefix(dao_id)
+				.filter(|(who, to)| {
+					members.binary_search(who).is_err() || members.binary_search(to).is_err()
+				})
+				.map(|(who, _)| who)
+				.collect::<Vec<_>>();
+			for who in stale {
+				Delegations::<T, I>::remove(dao_id, who);
+			}
+			if let Some(p) = prime {
+				Prime::<T, I>::insert(dao_id, p);
+			}
+			CollectiveMembers::<T, I>::insert(dao_id, &members);
+		} else {
+			CommitteeMembers::<T, I>::insert(dao_id, committee_id, &members);
+		}
+		if !incoming.is_empty() || !outgoing.is_empty() {
+			<T as Config<I>>::OnMembersChanged::on_members_changed(
+				dao_id,
+				committee_id,
+				&incoming,
+				&outgoing,
+				&members,
+			);
 		}
-		CollectiveMembers::<T, I>::insert(dao_id, members);
 
 		Ok(())
 	}
+
+	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
+	fn remove_proposal(index: ProposalIndex, dao_id: T::DaoId) -> u32 {
+		// remove proposal and vote
+		if let Some(preimage) = ProposalOf::<T, I>::take(dao_id, index) {
+			let proposal_hash = preimage.hash;
+			dao::Pallet::<T>::unrequest_preimage(dao_id, proposal_hash);
+			ProposalIndexes::<T, I>::mutate_exists(dao_id, proposal_hash, |indexes| {
+				if let Some(v) = indexes {
+					v.retain(|i| *i != index);
+					if v.is_empty() {
+						*indexes = None;
+					}
+				}
+			});
+		}
+		Voting::<T, I>::remove(dao_id, index);
+		MetadataOf::<T, I>::remove(dao_id, index);
+		CoolingOff::<T, I>::remove(dao_id, index);
+		ProposerOf::<T, I>::remove(dao_id, index);
+		let num_proposals = Proposals::<T, I>::mutate(dao_id, |proposals| {
+			proposals.retain(|i| *i != index);
+			proposals.len() + 1 // calculate weight based on original length
+		});
+		num_proposals as u32
+	}
+}
+
+impl<T: Config<I>, I: 'static> SetCollectiveMembers<T::AccountId, T::DaoId, DispatchError>
+	for Pallet<T, I>
+{
+	fn set_members_sorted(
+		dao_id: T::DaoId,
+		members: &[T::AccountId],
+		prime: Option<T::AccountId>,
+	) -> Result<(), DispatchError> {
+		Self::do_set_members(dao_id, DEFAULT_COMMITTEE, members, prime)
+	}
 }
 
 #[allow(non_snake_case)]
 impl<T: Config<I>, I: 'static>
-	EnsureOriginWithArg<<T as pallet::Config<I>>::Origin, (T::DaoId, T::CallId)> for Pallet<T, I>
+	EnsureOriginWithArg<
+		<T as pallet::Config<I>>::Origin,
+		(T::DaoId, CommitteeId, T::CallId, Option<u128>),
+	> for Pallet<T, I>
 {
 	type Success = <T as dao::Config>::DaoId;
 
 	fn try_origin(
 		o: <T as Config<I>>::Origin,
-		a: &(T::DaoId, T::CallId),
+		a: &(T::DaoId, CommitteeId, T::CallId, Option<u128>),
 	) -> Result<Self::Success, <T as Config<I>>::Origin> {
-		let ensure = EnsureOrigins::<T, I>::get(a.0, a.1);
-		match ensure {
-			DoAsEnsureOrigin::Proportion(pro) => match pro {
-				Proportion::MoreThan(N, D) => o.into().and_then(|o| match o {
-					RawOrigin::Root(dao_id) if dao_id == a.0 => Ok(dao_id),
-					RawOrigin::Members(dao_id, n, m) if dao_id == a.0 && n * D > N * m =>
-						Ok(dao_id),
-					r => Err(<T as Config<I>>::Origin::from(r)),
-				}),
-				Proportion::AtLeast(N, D) => o.into().and_then(|o| match o {
-					RawOrigin::Root(dao_id) if dao_id == a.0 => Ok(dao_id),
-					RawOrigin::Members(dao_id, n, m) if dao_id == a.0 && n * D >= N * m =>
-						Ok(dao_id),
-					r => Err(<T as Config<I>>::Origin::from(r)),
-				}),
-			},
-			DoAsEnsureOrigin::Member => o.into().and_then(|o| match o {
-				RawOrigin::Root(dao_id) if dao_id == a.0 => Ok(dao_id),
-				RawOrigin::Member(dao_id) if dao_id == a.0 => Ok(dao_id),
-				r => Err(<T as Config<I>>::Origin::from(r)),
-			}),
-			DoAsEnsureOrigin::Members(N) => o.into().and_then(|o| match o {
-				RawOrigin::Root(dao_id) if dao_id == a.0 => Ok(dao_id),
-				RawOrigin::Members(dao_id, n, _m) if dao_id == a.0 && n >= N => Ok(dao_id),
-				r => Err(<T as Config<I>>::Origin::from(r)),
-			}),
-
-			_ => o.into().and_then(|o| match o {
-				RawOrigin::Root(dao_id) if dao_id == a.0 => Ok(dao_id),
-				r => Err(<T as Config<I>>::Origin::from(r)),
-			}),
+		let raw: Result<RawOrigin<T::DaoId, I>, _> = o.clone().into();
+		let signed: Result<frame_system::RawOrigin<T::AccountId>, _> = o.clone().into();
+		let raw = raw.ok();
+		let signed = match signed {
+			Ok(frame_system::RawOrigin::Signed(who)) => Some(who),
+			_ => None,
+		};
+
+		// The root of the DAO meets every origin.
+		if let Some(RawOrigin::Root(dao_id)) = raw {
+			if dao_id == a.0 {
+				return Ok(dao_id)
+			}
+		}
+		let ensure = Self::ensure_origin_of(a.0, a.1, a.2, a.3);
+		if Self::origin_meets(a.0, a.1, raw.as_ref(), signed.as_ref(), &ensure) {
+			Ok(a.0)
+		} else {
+			Err(o)
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn successful_origin(
-		a: &(<T as dao::Config>::DaoId, <T as dao::Config>::CallId),
+		a: &(<T as dao::Config>::DaoId, CommitteeId, <T as dao::Config>::CallId, Option<u128>),
 	) -> <T as Config<I>>::Origin {
 		<T as Config<I>>::Origin::from(RawOrigin::Root(a.0))
 	}
@@ -1,16 +1,21 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 use crate as agency;
+use crate::traits::{ChangeMembers, SyncMembers};
 use frame_support::{
 	debug, parameter_types,
 	sp_tracing::debug,
 	traits::{ConstU16, ConstU32, ConstU64, Contains},
 };
 use frame_system;
-use primitives::{ids::Nft, traits::BaseCallFilter, types::MemberCount};
+use primitives::{
+	ids::Nft,
+	traits::{BaseCallFilter, CallMagnitude},
+	types::MemberCount,
+};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
@@ -27,6 +32,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		DAO: dao::{ Pallet, Call, Event<T>, Storage },
 		Agency: agency::{ Pallet, Call, Event<T>, Storage, Origin<T> },
 		Sudo: sudo::{ Pallet, Call, Event<T>, Storage },
@@ -70,6 +76,17 @@ impl TryFrom<Call> for u64 {
 	}
 }
 
+pub struct Magnitude;
+
+impl CallMagnitude<Call> for Magnitude {
+	fn magnitude(call: &Call) -> Option<u128> {
+		match call {
+			Call::Agency(agency::Call::set_max_members { max, .. }) => Some(*max as u128),
+			_ => None,
+		}
+	}
+}
+
 impl BaseCallFilter<Call> for Nft<u64> {
 	fn contains(&self, call: Call) -> bool {
 		match call {
@@ -87,6 +104,18 @@ impl BaseCallFilter<Call> for Nft<u64> {
 	}
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
 impl dao::Config for Test {
 	type Event = Event;
 	type Call = Call;
@@ -94,6 +123,11 @@ impl dao::Config for Test {
 	type DaoId = u64;
 	type ConcreteId = Nft<u64>;
 	type AfterCreate = ();
+	type CallMagnitude = Magnitude;
+	type PreimageCurrency = Balances;
+	type PreimageBaseDeposit = ConstU64<0>;
+	type PreimageByteDeposit = ConstU64<0>;
+	type MaxPreimageLen = ConstU32<1024>;
 	type WeightInfo = ();
 }
 
@@ -109,13 +143,30 @@ impl Contains<Call> for BaseCall {
 	}
 }
 
+parameter_types! {
+	pub static Synced: Vec<u64> = vec![];
+}
+
+/// Records the members of committee 1 as a pallet kept in sync with it would.
+pub struct SyncedMembers;
+impl ChangeMembers<u64, u64> for SyncedMembers {
+	fn change_members_sorted(_dao_id: u64, _incoming: &[u64], _outgoing: &[u64], new: &[u64]) {
+		Synced::set(new.to_vec());
+	}
+}
+
 impl agency::Config for Test {
 	type Event = Event;
 	type Origin = Origin;
 	type Proposal = Call;
 	type CollectiveBaseCallFilter = BaseCall;
-	type DefaultVote = agency::PrimeDefaultVote;
 	type MaxMembersForSystem = MaxMembersForSystem;
+	type MaxTitleLength = ConstU32<64>;
+	type MaxCommitteeNameLength = ConstU32<32>;
+	type MaxClosesPerBlock = ConstU32<10>;
+	type OnMembersChanged = SyncMembers<SyncedMembers, ConstU32<1>>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,40 @@
+// Copyright 2022 daos-org.
+// This file is part of DAOS
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime api for reading agency motions.
+//!
+//! The runtime implements it by forwarding to `Pallet::motion_metadata` and
+//! `Pallet::proposal_indexes`.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use primitives::types::{ProposalIndex, ProposalMetadata};
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	pub trait AgencyApi<DaoId, Hash>
+	where
+		DaoId: Codec,
+		Hash: Codec,
+	{
+		/// The title and description hash of a current motion.
+		fn motion_metadata(dao_id: DaoId, index: ProposalIndex) -> Option<ProposalMetadata<Vec<u8>, Vec<u8>>>;
+
+		/// The indexes of the current motions of a call hash.
+		fn motion_indexes(dao_id: DaoId, proposal_hash: Hash) -> Vec<ProposalIndex>;
+	}
+}
@@ -4,20 +4,40 @@
 use super::*;
 use crate::mock::{Call, Origin, *};
 use frame_support::{assert_noop, assert_ok, debug};
+use frame_support::traits::Hooks;
 use primitives::{
+	constant::{MAX_ENSURE_ORIGIN_DEPTH, MAX_MAGNITUDE_TIERS, MAX_PRESET_CALLS},
 	ids::Nft,
+	traits::CallMagnitude,
 	types::Proportion::{AtLeast, MoreThan},
 };
-use sp_runtime::traits::BlakeTwo256;
+use sp_core::H256;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash},
+};
 use sp_std::vec;
 use sudo;
 
 pub const ALICE: u64 = 1;
 
+/// The dao id, committee id, call id and magnitude an origin is checked against.
+type OriginArg = (u64, u32, u64, Option<u128>);
+
 pub fn create_dao() {
 	dao::Pallet::<Test>::create_dao(Origin::signed(ALICE), Nft(0u64), vec![1; 4]).unwrap();
 }
 
+/// Note a call in the preimage store of the DAO, unless it is noted already.
+pub fn note(call: Call) -> PreimageRef<H256> {
+	let bytes = call.encode();
+	let preimage = PreimageRef { hash: BlakeTwo256::hash(&bytes[..]), len: bytes.len() as u32 };
+	if !dao::PreimageFor::<Test>::contains_key(0u64, preimage.hash) {
+		assert_ok!(dao::Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, bytes));
+	}
+	preimage
+}
+
 pub fn set_members() {
 	crate::CollectiveMembers::<Test>::insert(0u64, vec![ALICE, 2u64, 3u64, 4u64])
 }
@@ -122,27 +142,30 @@ fn sudo_set_xxx() {
 #[test]
 fn set_members_sorted_should_work() {
 	new_test_ext().execute_with(|| {
-		set_sudo();
+		set_origin_for_0_1();
 
 		let set_max_members =
 			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
 
 		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
 			dao_id: 0u64,
+			committee_id: 0u32,
 			call: Box::new(set_max_members),
 		});
-		let hash = BlakeTwo256::hash_of(&do_as_agency);
 
 		assert_ok!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		));
 
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 0, false));
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, hash, 0, true));
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(4u64), 0, hash, 0, false));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, false));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 0, true));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(4u64), 0, 0, false));
 
 		let members: Vec<u64> = vec![3, 4, 5, 6];
 		let members_1: Vec<u64> = vec![1, 2, 3, 4, 8];
@@ -162,17 +185,20 @@ fn execute() {
 
 		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
 			dao_id: 0u64,
+			committee_id: 0u32,
 			call: Box::new(set_max_members),
 		});
 
 		let do_as_agency_fail = Call::DoAs(daos_doas::Call::do_as_agency {
 			dao_id: 0u64,
+			committee_id: 0u32,
 			call: Box::new(do_as_agency.clone()),
 		});
 
 		assert!(crate::Pallet::<Test>::execute(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			Box::new(do_as_agency_fail)
 		)
 		.is_ok());
@@ -180,6 +206,7 @@ fn execute() {
 		assert_ok!(crate::Pallet::<Test>::execute(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			Box::new(do_as_agency)
 		));
 
@@ -195,98 +222,112 @@ fn proposal_vote_close_should_work() {
 			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
 		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
 			dao_id: 0u64,
+			committee_id: 0u32,
 			call: Box::new(set_max_members),
 		});
-		let hash = BlakeTwo256::hash_of(&do_as_agency);
 
 		assert!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			10,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		)
 		.is_err());
 		MaxProposals::<Test>::insert(0u64, 0);
 		assert!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		)
 		.is_err());
 		MaxProposals::<Test>::insert(0u64, 100);
 		assert_ok!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		));
 
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 0, true));
-		assert!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 0, true).is_err());
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 0, false));
-		assert!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 0, false).is_err());
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 0, true));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, true));
+		assert!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, true).is_err());
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, false));
+		assert!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, false).is_err());
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, true));
 
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, hash, 0, true));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 0, true));
 
-		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, hash, 0));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0));
 		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
 		MaxMembers::<Test>::insert(0u64, 50);
 		assert_ok!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		));
 
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, hash, 1, false));
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, hash, 1, false));
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(4u64), 0, hash, 1, false));
-		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, hash, 1));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 1, false));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 1, false));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(4u64), 0, 1, false));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 1));
 		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 50);
 
 		assert_ok!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		));
-		assert!(crate::Pallet::<Test>::propose(
-			Origin::signed(ALICE),
-			0u64,
-			2,
-			Box::new(do_as_agency.clone())
-		)
-		.is_err());
-		assert!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, hash, 2).is_err());
+		assert!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 2).is_err());
 		Prime::<Test>::insert(0u64, ALICE);
-		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(ALICE), 0, hash, 2, false));
-		assert!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, hash, 2).is_err());
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(ALICE), 0, 2, false));
+		assert!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 2).is_err());
 		frame_system::Pallet::<Test>::set_block_number(100000);
-		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, hash, 2));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 2));
 
 		assert!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		)
 		.is_ok());
 		Prime::<Test>::insert(0u64, ALICE);
 		frame_system::Pallet::<Test>::set_block_number(200000);
-		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, hash, 3));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 3));
 
 		assert!(crate::Pallet::<Test>::propose(
 			Origin::signed(ALICE),
 			0u64,
+			0u32,
 			2,
-			Box::new(do_as_agency.clone())
+			note(do_as_agency.clone()),
+			None,
+			None
 		)
 		.is_ok());
 
 		let disapprove_proposal =
-			Call::Agency(crate::Call::disapprove_proposal { dao_id: 0u64, proposal_hash: hash });
+			Call::Agency(crate::Call::disapprove_proposal { dao_id: 0u64, index: 4 });
 		assert_ok!(sudo::Pallet::<Test>::sudo(
 			Origin::signed(ALICE),
 			0u64,
@@ -294,3 +335,1082 @@ fn proposal_vote_close_should_work() {
 		));
 	});
 }
+
+#[test]
+fn propose_with_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+		note(do_as_agency.clone());
+
+		assert_noop!(
+			crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				2,
+				note(do_as_agency.clone()),
+				Some(vec![1; 65]),
+				None
+			),
+			Error::<Test>::TitleTooLong
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				2,
+				note(do_as_agency.clone()),
+				None,
+				Some(vec![1; 65])
+			),
+			Error::<Test>::DescriptionHashTooLong
+		);
+
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			Some(b"raise the member cap".to_vec()),
+			Some(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec())
+		));
+		assert_eq!(
+			crate::Pallet::<Test>::motion_metadata(0u64, 0),
+			Some(ProposalMetadata {
+				title: Some(b"raise the member cap".to_vec()),
+				description_hash: Some(
+					b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec()
+				),
+			})
+		);
+
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, true));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0));
+		assert!(crate::MetadataOf::<Test>::get(0u64, 0).is_none());
+	});
+}
+
+#[test]
+fn veto_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+		let hash = BlakeTwo256::hash_of(&do_as_agency);
+
+		let set_veto_holder =
+			Call::Agency(crate::Call::set_veto_holder { dao_id: 0u64, who: Some(5u64) });
+		assert!(sudo::Pallet::<Test>::sudo(Origin::signed(ALICE), 0u64, Box::new(set_veto_holder))
+			.is_ok());
+		assert_eq!(crate::Pallet::<Test>::veto_holder(0u64), None);
+		let set_veto_holder =
+			Call::Agency(crate::Call::set_veto_holder { dao_id: 0u64, who: Some(2u64) });
+		assert_ok!(sudo::Pallet::<Test>::sudo(
+			Origin::signed(ALICE),
+			0u64,
+			Box::new(set_veto_holder)
+		));
+		assert_eq!(crate::Pallet::<Test>::veto_holder(0u64), Some(2u64));
+
+		let now = frame_system::Pallet::<Test>::block_number();
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		assert_noop!(
+			crate::Pallet::<Test>::veto(Origin::signed(2u64), 0u64, 0),
+			Error::<Test>::NotCoolingOff
+		);
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 0, true));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0));
+		assert_eq!(crate::Pallet::<Test>::cooling_off(0u64, 0), Some((now as u32 + 100, 2, 4)));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 10);
+		assert_noop!(
+			crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0),
+			Error::<Test>::TooEarly
+		);
+
+		assert_noop!(
+			crate::Pallet::<Test>::veto(Origin::signed(3u64), 0u64, 0),
+			Error::<Test>::NotVetoHolder
+		);
+		assert_ok!(crate::Pallet::<Test>::veto(Origin::signed(2u64), 0u64, 0));
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 0).is_none());
+		assert!(crate::Pallet::<Test>::cooling_off(0u64, 0).is_none());
+		assert_noop!(
+			crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				2,
+				note(do_as_agency.clone()),
+				None,
+				None
+			),
+			Error::<Test>::ProposalBlacklisted
+		);
+
+		frame_system::Pallet::<Test>::set_block_number(now + 500);
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		assert!(crate::Pallet::<Test>::blacklist(0u64, hash).is_none());
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 1, true));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 1));
+		frame_system::Pallet::<Test>::set_block_number(now + 600);
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 1));
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 1).is_none());
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+	});
+}
+
+#[test]
+fn withdraw_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			Some(b"raise the member cap".to_vec()),
+			None
+		));
+		assert_eq!(crate::Pallet::<Test>::proposer_of(0u64, 0), Some(ALICE));
+
+		assert_noop!(
+			crate::Pallet::<Test>::withdraw(Origin::signed(2u64), 0u64, 0),
+			Error::<Test>::NotProposer
+		);
+		assert_ok!(crate::Pallet::<Test>::withdraw(Origin::signed(ALICE), 0u64, 0));
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 0).is_none());
+		assert!(crate::Pallet::<Test>::voting(0u64, 0).is_none());
+		assert!(crate::Pallet::<Test>::proposer_of(0u64, 0).is_none());
+		assert!(crate::Pallet::<Test>::motion_metadata(0u64, 0).is_none());
+		assert!(crate::Pallet::<Test>::proposals(0u64).is_empty());
+		assert_noop!(
+			crate::Pallet::<Test>::withdraw(Origin::signed(ALICE), 0u64, 0),
+			Error::<Test>::ProposalMissing
+		);
+	});
+}
+
+#[test]
+fn submit_signed_votes_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			3,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+
+		let sign = |who: u64, approve: bool| {
+			let payload = crate::Pallet::<Test>::signed_vote_payload(0u64, &who, 0, approve);
+			(who, 0, approve, TestSignature(who, payload))
+		};
+		let vote_of_2 = sign(2u64, true);
+		let (_, _, _, TestSignature(_, payload)) = sign(3u64, false);
+		assert_noop!(
+			crate::Pallet::<Test>::submit_signed_votes(
+				Origin::signed(9u64),
+				0u64,
+				vec![(3u64, 0, true, TestSignature(3u64, payload))]
+			),
+			Error::<Test>::BadSignature
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::submit_signed_votes(
+				Origin::signed(9u64),
+				0u64,
+				vec![vote_of_2.clone(), sign(5u64, true)]
+			),
+			Error::<Test>::NotMember
+		);
+
+		assert_ok!(crate::Pallet::<Test>::submit_signed_votes(
+			Origin::signed(9u64),
+			0u64,
+			vec![vote_of_2.clone(), sign(3u64, false)]
+		));
+		let voting = crate::Pallet::<Test>::voting(0u64, 0).unwrap();
+		assert_eq!((voting.ayes, voting.nays), (vec![ALICE, 2u64], vec![3u64]));
+		assert_eq!(crate::Pallet::<Test>::signed_vote_nonce(0u64, 2u64), 1);
+
+		// A signed vote can not be replayed.
+		assert_noop!(
+			crate::Pallet::<Test>::submit_signed_votes(Origin::signed(9u64), 0u64, vec![vote_of_2]),
+			Error::<Test>::BadSignature
+		);
+		assert_ok!(crate::Pallet::<Test>::submit_signed_votes(
+			Origin::signed(9u64),
+			0u64,
+			vec![sign(3u64, true)]
+		));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+	});
+}
+
+#[test]
+fn same_call_can_be_proposed_twice() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+		let hash = BlakeTwo256::hash_of(&do_as_agency);
+
+		for _ in 0..2 {
+			assert_ok!(crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				2,
+				note(do_as_agency.clone()),
+				None,
+				None
+			));
+		}
+		assert_eq!(crate::Pallet::<Test>::proposals(0u64), vec![0, 1]);
+		assert_eq!(crate::Pallet::<Test>::proposal_indexes(0u64, hash), vec![0, 1]);
+
+		assert_ok!(crate::Pallet::<Test>::withdraw(Origin::signed(ALICE), 0u64, 0));
+		assert_eq!(crate::Pallet::<Test>::proposal_indexes(0u64, hash), vec![1]);
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 1, true));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 1));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+		assert!(crate::Pallet::<Test>::proposal_indexes(0u64, hash).is_empty());
+		assert!(crate::Pallet::<Test>::proposals(0u64).is_empty());
+	});
+}
+
+#[test]
+fn delegate_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+
+		assert_noop!(
+			crate::Pallet::<Test>::delegate(Origin::signed(4u64), 0u64, 4u64),
+			Error::<Test>::SelfDelegation
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::delegate(Origin::signed(4u64), 0u64, 5u64),
+			Error::<Test>::NotMember
+		);
+		assert_ok!(crate::Pallet::<Test>::delegate(Origin::signed(4u64), 0u64, 3u64));
+		assert_noop!(
+			crate::Pallet::<Test>::delegate(Origin::signed(3u64), 0u64, 2u64),
+			Error::<Test>::DelegationChain
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::delegate(Origin::signed(2u64), 0u64, 4u64),
+			Error::<Test>::DelegationChain
+		);
+
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			3,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		// 4 follows 3, so the motion reaches its threshold of 3.
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 0, true));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(2u64), 0, 0));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+
+		// A direct vote overrides the delegation.
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			3,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 1, true));
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(4u64), 0, 1, false));
+		assert_eq!(
+			crate::Pallet::<Test>::tally(0u64, &crate::Pallet::<Test>::voting(0u64, 1).unwrap()),
+			(2, 1, 0)
+		);
+
+		assert_ok!(crate::Pallet::<Test>::undelegate(Origin::signed(4u64), 0u64));
+		assert!(crate::Pallet::<Test>::delegations(0u64, 4u64).is_none());
+		assert_noop!(
+			crate::Pallet::<Test>::undelegate(Origin::signed(4u64), 0u64),
+			Error::<Test>::NotDelegating
+		);
+
+		assert_ok!(crate::Pallet::<Test>::delegate(Origin::signed(2u64), 0u64, 3u64));
+		assert_ok!(crate::Pallet::<Test>::set_members_sorted(0u64, &[ALICE, 3u64, 4u64], None));
+		assert!(crate::Pallet::<Test>::delegations(0u64, 2u64).is_none());
+	});
+}
+
+#[test]
+fn abstain_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			3,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		assert_ok!(crate::Pallet::<Test>::abstain(Origin::signed(2u64), 0, 0));
+		assert_noop!(
+			crate::Pallet::<Test>::abstain(Origin::signed(2u64), 0, 0),
+			Error::<Test>::DuplicateVote
+		);
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, 0, true));
+		assert_ok!(crate::Pallet::<Test>::abstain(Origin::signed(2u64), 0, 0));
+		assert_ok!(crate::Pallet::<Test>::abstain(Origin::signed(3u64), 0, 0));
+		assert_eq!(
+			crate::Pallet::<Test>::tally(0u64, &crate::Pallet::<Test>::voting(0u64, 0).unwrap()),
+			(1, 0, 2)
+		);
+
+		// Two abstentions leave too few seats to reach the threshold.
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 10);
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 0).is_none());
+
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		assert_ok!(crate::Pallet::<Test>::abstain(Origin::signed(3u64), 0, 1));
+		// 2 and 4 have not voted and follow the prime, while 3 stays abstained.
+		Prime::<Test>::insert(0u64, ALICE);
+		frame_system::Pallet::<Test>::set_block_number(1000);
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 1));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+	});
+}
+
+#[test]
+fn on_initialize_should_close_due_motions() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(set_max_members),
+		});
+
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		let end = crate::Pallet::<Test>::voting(0u64, 0).unwrap().end;
+		assert_eq!(crate::Pallet::<Test>::motions_due(end), vec![(0u64, 0)]);
+		assert_noop!(
+			crate::Pallet::<Test>::close(Origin::signed(4u64), 0, 0),
+			Error::<Test>::TooEarly
+		);
+
+		Prime::<Test>::insert(0u64, ALICE);
+		crate::Pallet::<Test>::on_initialize(end as u64 - 1);
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 0).is_some());
+		frame_system::Pallet::<Test>::set_block_number(end as u64);
+		// the weight of the executed call is counted along with the close.
+		let close = <() as crate::weights::WeightInfo>::close();
+		assert_eq!(
+			crate::Pallet::<Test>::on_initialize(end as u64),
+			close.saturating_add(do_as_agency.get_dispatch_info().weight)
+		);
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 0).is_none());
+		assert!(crate::Pallet::<Test>::motions_due(end).is_empty());
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+
+		// Motions over the per block limit wait until there is weight left to close them.
+		let due = (0..12u32).map(|i| (0u64, i)).collect::<Vec<_>>();
+		MotionsDue::<Test>::insert(2000u32, due.clone());
+		crate::Pallet::<Test>::on_initialize(2000);
+		assert_eq!(crate::Pallet::<Test>::motions_overdue(), due[10..].to_vec());
+		crate::Pallet::<Test>::on_idle(2000, Weight::zero());
+		assert_eq!(crate::Pallet::<Test>::motions_overdue(), due[10..].to_vec());
+		crate::Pallet::<Test>::on_idle(2000, Weight::MAX);
+		assert!(crate::Pallet::<Test>::motions_overdue().is_empty());
+
+		// A motion whose call can no longer be decoded is removed.
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		let end = crate::Pallet::<Test>::voting(0u64, 1).unwrap().end;
+		let preimage = crate::Pallet::<Test>::proposal_of(0u64, 1).unwrap();
+		dao::PreimageFor::<Test>::insert(0u64, preimage.hash, BoundedVec::truncate_from(vec![1]));
+		frame_system::Pallet::<Test>::set_block_number(end as u64);
+		crate::Pallet::<Test>::on_initialize(end as u64);
+		assert!(crate::Pallet::<Test>::proposal_of(0u64, 1).is_none());
+		assert!(crate::Pallet::<Test>::voting(0u64, 1).is_none());
+	});
+}
+
+#[test]
+fn committee_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		assert!(crate::Pallet::<Test>::create_committee(
+			Origin::signed(ALICE),
+			0u64,
+			b"tech".to_vec(),
+			3
+		)
+		.is_err());
+		assert_noop!(
+			crate::Pallet::<Test>::create_committee(
+				Origin::signed(dao_account),
+				0u64,
+				vec![1; 33],
+				3
+			),
+			Error::<Test>::CommitteeNameTooLong
+		);
+		assert_ok!(crate::Pallet::<Test>::create_committee(
+			Origin::signed(dao_account),
+			0u64,
+			b"tech".to_vec(),
+			3
+		));
+		assert_eq!(crate::Pallet::<Test>::committees(0u64, 1u32).unwrap().threshold, 3);
+		assert_eq!(crate::Pallet::<Test>::next_committee_id(0u64), 2);
+		assert_noop!(
+			crate::Pallet::<Test>::set_committee_members(
+				Origin::signed(dao_account),
+				0u64,
+				2u32,
+				vec![2u64, 3u64, 5u64]
+			),
+			Error::<Test>::CommitteeMissing
+		);
+		assert_ok!(crate::Pallet::<Test>::set_committee_members(
+			Origin::signed(dao_account),
+			0u64,
+			1u32,
+			vec![5u64, 3u64, 2u64]
+		));
+		assert_eq!(crate::Pallet::<Test>::members_of(0u64, 1u32), vec![2u64, 3u64, 5u64]);
+		assert_eq!(crate::Pallet::<Test>::members_of(0u64, 0u32), vec![ALICE, 2u64, 3u64, 4u64]);
+		// Only the members of committee 1 are kept in sync.
+		assert_eq!(Synced::get(), vec![2u64, 3u64, 5u64]);
+		assert_ok!(crate::Pallet::<Test>::set_members_sorted(0u64, &[ALICE, 2u64], None));
+		assert_eq!(Synced::get(), vec![2u64, 3u64, 5u64]);
+		assert_ok!(crate::Pallet::<Test>::set_committee_ensure_origin(
+			Origin::signed(dao_account),
+			0u64,
+			1u32,
+			0u64,
+			DoAsEnsureOrigin::Members(3u32)
+		));
+		assert_eq!(
+			crate::Pallet::<Test>::ensure_origin_of(0u64, 1u32, 0u64, None),
+			DoAsEnsureOrigin::Members(3u32)
+		);
+		assert_eq!(
+			crate::Pallet::<Test>::ensure_origin_of(0u64, 0u32, 0u64, None),
+			DoAsEnsureOrigin::Members(2u32)
+		);
+
+		// the committee id of the origin must match the one of the call.
+		let check = |origin: RawOrigin<u64, ()>, committee_id: u32| {
+			<crate::Pallet<Test> as EnsureOriginWithArg<Origin, OriginArg>>::try_origin(
+				origin.into(),
+				&(0u64, committee_id, 0u64, None),
+			)
+			.is_ok()
+		};
+		assert!(check(RawOrigin::Members(0u64, 1u32, 3, 3), 1u32));
+		assert!(!check(RawOrigin::Members(0u64, 1u32, 2, 3), 1u32));
+		assert!(!check(RawOrigin::Members(0u64, 0u32, 3, 4), 1u32));
+		assert!(!check(RawOrigin::Members(0u64, 1u32, 3, 3), 0u32));
+		assert!(check(RawOrigin::Root(0u64), 1u32));
+
+		let set_max_members =
+			Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 100u32 });
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 1u32,
+			call: Box::new(set_max_members),
+		});
+		note(do_as_agency.clone());
+		assert_noop!(
+			crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				1u32,
+				3,
+				note(do_as_agency.clone()),
+				None,
+				None
+			),
+			Error::<Test>::NotMember
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::propose(
+				Origin::signed(2u64),
+				0u64,
+				1u32,
+				2,
+				note(do_as_agency.clone()),
+				None,
+				None
+			),
+			Error::<Test>::ThresholdTooLow
+		);
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(2u64),
+			0u64,
+			1u32,
+			3,
+			note(do_as_agency.clone()),
+			None,
+			None
+		));
+		assert_noop!(
+			crate::Pallet::<Test>::vote(Origin::signed(4u64), 0, 0, true),
+			Error::<Test>::NotMember
+		);
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(3u64), 0, 0, true));
+		// Delegations do not count in motions of other committees.
+		crate::Delegations::<Test>::insert(0u64, 5u64, 3u64);
+		assert_eq!(
+			crate::Pallet::<Test>::tally(0u64, &crate::Pallet::<Test>::voting(0u64, 0).unwrap()),
+			(2, 0, 0)
+		);
+		crate::Delegations::<Test>::remove(0u64, 5u64);
+		assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(5u64), 0, 0, true));
+		assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0u64, 0));
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 100);
+	});
+}
+
+#[test]
+fn default_vote_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		set_origin_for_0_1();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		crate::Prime::<Test>::insert(0u64, 2u64);
+		let mut now = 0u64;
+		let mut vote_on = |max: u32, policy: DefaultVotePolicy| {
+			assert!(crate::Pallet::<Test>::set_default_vote_policy(
+				Origin::signed(ALICE),
+				0u64,
+				policy
+			)
+			.is_err());
+			assert_ok!(crate::Pallet::<Test>::set_default_vote_policy(
+				Origin::signed(dao_account),
+				0u64,
+				policy
+			));
+			assert_eq!(crate::Pallet::<Test>::default_vote_policy(0u64), policy);
+			let set_max_members =
+				Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max });
+			let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+				dao_id: 0u64,
+				committee_id: 0u32,
+				call: Box::new(set_max_members),
+			});
+			let index = crate::Pallet::<Test>::proposal_count(0u64);
+			assert_ok!(crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				3,
+				note(do_as_agency),
+				None,
+				None
+			));
+			assert_ok!(crate::Pallet::<Test>::vote(Origin::signed(2u64), 0, index, true));
+			assert_noop!(
+				crate::Pallet::<Test>::close(Origin::signed(4u64), 0u64, index),
+				Error::<Test>::TooEarly
+			);
+			now += 1000;
+			frame_system::Pallet::<Test>::set_block_number(now);
+			assert_ok!(crate::Pallet::<Test>::close(Origin::signed(4u64), 0u64, index));
+		};
+
+		// 2 of 4 voted aye, the prime among them.
+		vote_on(50, DefaultVotePolicy::AbstainAsNay);
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 10);
+		vote_on(60, DefaultVotePolicy::PrimeDecides);
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 60);
+		vote_on(70, DefaultVotePolicy::MajorityThenPrime);
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 70);
+		crate::Prime::<Test>::remove(0u64);
+		vote_on(80, DefaultVotePolicy::MajorityThenPrime);
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 70);
+		// all the members who voted approved.
+		vote_on(90, DefaultVotePolicy::AbstainIgnored);
+		assert_eq!(crate::Pallet::<Test>::max_members(0u64), 90);
+	});
+}
+
+#[test]
+fn composed_origin_should_work() {
+	new_test_ext().execute_with(|| {
+		set_sudo();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let set = |ensure: DoAsEnsureOriginOf<Test>| {
+			crate::Pallet::<Test>::set_ensure_origin_for_every_call(
+				Origin::signed(dao_account),
+				0u64,
+				0u64,
+				ensure,
+			)
+		};
+		let check = |origin: Origin, committee_id: u32| {
+			<crate::Pallet<Test> as EnsureOriginWithArg<Origin, OriginArg>>::try_origin(
+				origin,
+				&(0u64, committee_id, 0u64, None),
+			)
+			.is_ok()
+		};
+		let members =
+			|c: u32, n: u32, m: u32| -> Origin { RawOrigin::Members(0u64, c, n, m).into() };
+
+		// an agency 2/3 or the DAO account, which square referendums dispatch with.
+		assert_ok!(set(DoAsEnsureOrigin::Or(
+			Box::new(DoAsEnsureOrigin::Proportion(AtLeast(2, 3))),
+			Box::new(DoAsEnsureOrigin::Account(dao_account)),
+		)));
+		assert!(check(members(0, 3, 4), 0));
+		assert!(!check(members(0, 2, 4), 0));
+		assert!(check(Origin::signed(dao_account), 0));
+		assert!(!check(Origin::signed(ALICE), 0));
+
+		assert_ok!(set(DoAsEnsureOrigin::And(
+			Box::new(DoAsEnsureOrigin::Members(2)),
+			Box::new(DoAsEnsureOrigin::Proportion(MoreThan(1, 2))),
+		)));
+		assert!(check(members(0, 2, 3), 0));
+		assert!(!check(members(0, 2, 5), 0));
+		assert!(!check(members(0, 1, 1), 0));
+
+		assert_ok!(set(DoAsEnsureOrigin::AnyOf(vec![1u32, 2u32].try_into().unwrap())));
+		assert!(check(members(1, 1, 3), 0));
+		assert!(check(members(2, 2, 2), 0));
+		assert!(!check(members(0, 4, 4), 0));
+		assert!(check(RawOrigin::Root(0u64).into(), 0));
+
+		let mut deep = DoAsEnsureOrigin::Member;
+		for _ in 0..MAX_ENSURE_ORIGIN_DEPTH {
+			deep = DoAsEnsureOrigin::Or(Box::new(deep), Box::new(DoAsEnsureOrigin::Root));
+		}
+		assert_noop!(set(deep), Error::<Test>::OriginTooDeep);
+		assert_noop!(
+			set(DoAsEnsureOrigin::Or(
+				Box::new(DoAsEnsureOrigin::Member),
+				Box::new(DoAsEnsureOrigin::Proportion(AtLeast(3, 2))),
+			)),
+			Error::<Test>::ProportionErr
+		);
+	});
+}
+
+#[test]
+pub fn ensure_origin_tiers_should_work() {
+	new_test_ext().execute_with(|| {
+		set_sudo();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let set = |tiers: Vec<(u128, DoAsEnsureOriginOf<Test>)>| {
+			crate::Pallet::<Test>::set_ensure_origin_tiers(
+				Origin::signed(dao_account),
+				0u64,
+				0u32,
+				0u64,
+				tiers,
+			)
+		};
+		assert_noop!(
+			crate::Pallet::<Test>::set_ensure_origin_tiers(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				0u64,
+				vec![]
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_noop!(
+			set(vec![(100, DoAsEnsureOrigin::Members(3)), (100, DoAsEnsureOrigin::Members(4))]),
+			Error::<Test>::TiersNotAscending
+		);
+		assert_noop!(
+			set((0..=MAX_MAGNITUDE_TIERS as u128).map(|i| (i, DoAsEnsureOrigin::Root)).collect()),
+			Error::<Test>::TooManyTiers
+		);
+		assert_noop!(
+			set(vec![(100, DoAsEnsureOrigin::Proportion(AtLeast(3, 2)))]),
+			Error::<Test>::ProportionErr
+		);
+
+		assert_ok!(crate::Pallet::<Test>::set_ensure_origin_for_every_call(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			DoAsEnsureOrigin::Members(2)
+		));
+		assert_ok!(set(vec![(100, DoAsEnsureOrigin::Members(3)), (1000, DoAsEnsureOrigin::Root)]));
+		let ensure_origin_of = |magnitude: Option<u128>| {
+			crate::Pallet::<Test>::ensure_origin_of(0u64, 0u32, 0u64, magnitude)
+		};
+		assert_eq!(ensure_origin_of(None), DoAsEnsureOrigin::Members(2));
+		assert_eq!(ensure_origin_of(Some(99)), DoAsEnsureOrigin::Members(2));
+		assert_eq!(ensure_origin_of(Some(100)), DoAsEnsureOrigin::Members(3));
+		assert_eq!(ensure_origin_of(Some(999)), DoAsEnsureOrigin::Members(3));
+		assert_eq!(ensure_origin_of(Some(1000)), DoAsEnsureOrigin::Root);
+
+		// the magnitude of `set_max_members` is the new maximum.
+		let check = |yes: u32, max: u32| {
+			let call = Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max });
+			<crate::Pallet<Test> as EnsureOriginWithArg<Origin, OriginArg>>::try_origin(
+				RawOrigin::Members(0u64, 0u32, yes, 4).into(),
+				&(0u64, 0u32, 0u64, Magnitude::magnitude(&call)),
+			)
+			.is_ok()
+		};
+		assert!(check(2, 50));
+		assert!(!check(2, 100));
+		assert!(check(3, 100));
+		assert!(!check(4, 1000));
+		assert!(check(4, 99));
+
+		assert_ok!(set(vec![]));
+		assert!(!EnsureOriginTiers::<Test>::contains_key((0u64, 0u32, 0u64)));
+		assert!(check(2, 1000));
+	});
+}
+
+#[test]
+pub fn ensure_origin_presets_should_work() {
+	new_test_ext().execute_with(|| {
+		set_sudo();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		assert_ok!(crate::Pallet::<Test>::set_ensure_origins(
+			Origin::signed(dao_account),
+			0u64,
+			vec![(1u64, DoAsEnsureOrigin::Member), (2u64, DoAsEnsureOrigin::Members(3))]
+		));
+		assert_eq!(crate::Pallet::<Test>::ensures(0u64, 1u64), DoAsEnsureOrigin::Member);
+		assert_eq!(crate::Pallet::<Test>::ensures(0u64, 2u64), DoAsEnsureOrigin::Members(3));
+		assert_noop!(
+			crate::Pallet::<Test>::set_ensure_origins(
+				Origin::signed(dao_account),
+				0u64,
+				vec![(3u64, DoAsEnsureOrigin::Proportion(AtLeast(3, 2)))]
+			),
+			Error::<Test>::ProportionErr
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::set_ensure_origins(
+				Origin::signed(dao_account),
+				0u64,
+				vec![(3u64, DoAsEnsureOrigin::Member); MAX_PRESET_CALLS as usize + 1]
+			),
+			Error::<Test>::TooManyCalls
+		);
+
+		let conservative = vec![
+			(1u64, DoAsEnsureOrigin::Root),
+			(2u64, DoAsEnsureOrigin::Proportion(AtLeast(3, 4))),
+		];
+		assert_noop!(
+			crate::Pallet::<Test>::register_ensure_origin_preset(
+				Origin::signed(dao_account),
+				b"conservative".to_vec(),
+				conservative.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::apply_ensure_origin_preset(
+				Origin::signed(dao_account),
+				0u64,
+				b"conservative".to_vec()
+			),
+			Error::<Test>::PresetMissing
+		);
+		assert_ok!(crate::Pallet::<Test>::register_ensure_origin_preset(
+			Origin::root(),
+			b"conservative".to_vec(),
+			conservative
+		));
+		assert_noop!(
+			crate::Pallet::<Test>::apply_ensure_origin_preset(
+				Origin::signed(ALICE),
+				0u64,
+				b"conservative".to_vec()
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_ok!(crate::Pallet::<Test>::apply_ensure_origin_preset(
+			Origin::signed(dao_account),
+			0u64,
+			b"conservative".to_vec()
+		));
+		assert_eq!(crate::Pallet::<Test>::ensures(0u64, 1u64), DoAsEnsureOrigin::Root);
+		assert_eq!(
+			crate::Pallet::<Test>::ensures(0u64, 2u64),
+			DoAsEnsureOrigin::Proportion(AtLeast(3, 4))
+		);
+
+		assert_ok!(crate::Pallet::<Test>::register_ensure_origin_preset(
+			Origin::root(),
+			b"conservative".to_vec(),
+			vec![]
+		));
+		assert!(crate::Pallet::<Test>::ensure_origin_presets(PresetName::truncate_from(
+			b"conservative".to_vec()
+		))
+		.is_none());
+	});
+}
+
+#[test]
+pub fn allowance_should_work() {
+	new_test_ext().execute_with(|| {
+		set_sudo();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let set = |allowance: Option<Allowance>| {
+			crate::Pallet::<Test>::set_allowance(
+				Origin::signed(dao_account),
+				0u64,
+				ALICE,
+				allowance,
+			)
+		};
+		let allowance = Allowance { period: 100, max_executions: 2, max_value: Some(150) };
+		assert_noop!(
+			crate::Pallet::<Test>::set_allowance(
+				Origin::signed(ALICE),
+				0u64,
+				ALICE,
+				Some(allowance)
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_noop!(
+			set(Some(Allowance { period: 0, ..allowance })),
+			Error::<Test>::AllowancePeriodZero
+		);
+		assert_ok!(set(Some(allowance)));
+
+		// the magnitude of `set_max_members` is the new maximum.
+		let execute = |call: crate::Call<Test>| {
+			crate::Pallet::<Test>::execute(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				Box::new(Call::Agency(call)),
+			)
+		};
+		let set_max_members = |max: u32| crate::Call::set_max_members { dao_id: 0u64, max };
+		let set_motion_duration = crate::Call::set_motion_duration { dao_id: 0u64, duration: 10 };
+
+		frame_system::Pallet::<Test>::set_block_number(150);
+		assert_ok!(execute(set_max_members(100)));
+		assert_eq!(
+			crate::Pallet::<Test>::allowance_usage(0u64, ALICE),
+			AllowanceUsage { period_start: 100, executions: 1, value: 100 }
+		);
+		assert_noop!(execute(set_max_members(60)), Error::<Test>::AllowanceValueExceeded);
+		assert_ok!(execute(set_motion_duration.clone()));
+		assert_noop!(execute(set_max_members(10)), Error::<Test>::AllowanceExhausted);
+		// other members are not limited.
+		assert_ok!(crate::Pallet::<Test>::execute(
+			Origin::signed(2u64),
+			0u64,
+			0u32,
+			Box::new(Call::Agency(set_max_members(1000)))
+		));
+
+		// the allowance is back in the next period.
+		frame_system::Pallet::<Test>::set_block_number(200);
+		assert_ok!(execute(set_max_members(150)));
+		assert_noop!(execute(set_max_members(1)), Error::<Test>::AllowanceValueExceeded);
+		// wrapping the call in `do_as_agency` does not hide its value.
+		let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
+			dao_id: 0u64,
+			committee_id: 0u32,
+			call: Box::new(Call::Agency(set_max_members(1))),
+		});
+		assert_noop!(
+			crate::Pallet::<Test>::execute(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				Box::new(do_as_agency)
+			),
+			Error::<Test>::AllowanceValueExceeded
+		);
+
+		assert_ok!(set(None));
+		assert_eq!(crate::Pallet::<Test>::allowance_usage(0u64, ALICE), Default::default());
+		assert_ok!(execute(set_max_members(1000)));
+		assert_ok!(execute(set_max_members(1000)));
+		assert_ok!(execute(set_motion_duration));
+	});
+}
+
+#[test]
+pub fn threshold_should_meet_origin() {
+	new_test_ext().execute_with(|| {
+		set_sudo();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let motion = |committee_id: u32, max: u32| {
+			let set_max_members =
+				Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max });
+			Call::DoAs(daos_doas::Call::do_as_agency {
+				dao_id: 0u64,
+				committee_id,
+				call: Box::new(set_max_members),
+			})
+		};
+		let propose = |threshold: u32, committee_id: u32, max: u32| {
+			crate::Pallet::<Test>::propose(
+				Origin::signed(ALICE),
+				0u64,
+				0u32,
+				threshold,
+				note(motion(committee_id, max)),
+				None,
+				None,
+			)
+		};
+		// noted up front, so that the failing proposals do not mutate the storage.
+		for (committee_id, max) in [(0, 10), (1, 11), (0, 100), (0, 99)] {
+			note(motion(committee_id, max));
+		}
+
+		// the default origin is the root, which no motion meets.
+		assert_noop!(propose(4, 0, 10), Error::<Test>::ThresholdCannotMeetOrigin);
+
+		assert_ok!(crate::Pallet::<Test>::set_ensure_origin_for_every_call(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			DoAsEnsureOrigin::Members(3)
+		));
+		assert_noop!(propose(2, 0, 10), Error::<Test>::ThresholdCannotMeetOrigin);
+		assert_ok!(propose(3, 0, 10));
+		// the call must be done by the committee of the motion.
+		assert_noop!(propose(4, 1, 11), Error::<Test>::ThresholdCannotMeetOrigin);
+
+		// 3 of the 4 members are not more than 3/4.
+		assert_ok!(crate::Pallet::<Test>::set_ensure_origin_tiers(
+			Origin::signed(dao_account),
+			0u64,
+			0u32,
+			0u64,
+			vec![(100, DoAsEnsureOrigin::Proportion(MoreThan(3, 4)))]
+		));
+		assert_noop!(propose(3, 0, 100), Error::<Test>::ThresholdCannotMeetOrigin);
+		assert_ok!(propose(4, 0, 100));
+		assert_ok!(propose(3, 0, 99));
+
+		// other proposals are not checked.
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			2,
+			note(Call::Agency(crate::Call::set_max_members { dao_id: 0u64, max: 10 })),
+			None,
+			None,
+		));
+	});
+}
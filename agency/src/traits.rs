@@ -53,34 +53,7 @@ pub trait ChangeMembers<AccountId: Clone + Ord, DaoId: Clone + Default + Copy> {
 		new_members: &[AccountId],
 		old_members: &[AccountId],
 	) -> (Vec<AccountId>, Vec<AccountId>) {
-		let mut old_iter = old_members.iter();
-		let mut new_iter = new_members.iter();
-		let mut incoming = Vec::new();
-		let mut outgoing = Vec::new();
-		let mut old_i = old_iter.next();
-		let mut new_i = new_iter.next();
-		loop {
-			match (old_i, new_i) {
-				(None, None) => break,
-				(Some(old), Some(new)) if old == new => {
-					old_i = old_iter.next();
-					new_i = new_iter.next();
-				},
-				(Some(old), Some(new)) if old < new => {
-					outgoing.push(old.clone());
-					old_i = old_iter.next();
-				},
-				(Some(old), None) => {
-					outgoing.push(old.clone());
-					old_i = old_iter.next();
-				},
-				(_, Some(new)) => {
-					incoming.push(new.clone());
-					new_i = new_iter.next();
-				},
-			}
-		}
-		(incoming, outgoing)
+		compute_members_diff_sorted(new_members, old_members)
 	}
 
 	/// Set the prime member.
@@ -92,6 +65,92 @@ pub trait ChangeMembers<AccountId: Clone + Ord, DaoId: Clone + Default + Copy> {
 	}
 }
 
+/// Compute diff between new and old members; they **must already be sorted**.
+///
+/// Returns incoming and outgoing members.
+pub fn compute_members_diff_sorted<AccountId: Clone + Ord>(
+	new_members: &[AccountId],
+	old_members: &[AccountId],
+) -> (Vec<AccountId>, Vec<AccountId>) {
+	let mut old_iter = old_members.iter();
+	let mut new_iter = new_members.iter();
+	let mut incoming = Vec::new();
+	let mut outgoing = Vec::new();
+	let mut old_i = old_iter.next();
+	let mut new_i = new_iter.next();
+	loop {
+		match (old_i, new_i) {
+			(None, None) => break,
+			(Some(old), Some(new)) if old == new => {
+				old_i = old_iter.next();
+				new_i = new_iter.next();
+			},
+			(Some(old), Some(new)) if old < new => {
+				outgoing.push(old.clone());
+				old_i = old_iter.next();
+			},
+			(Some(old), None) => {
+				outgoing.push(old.clone());
+				old_i = old_iter.next();
+			},
+			(_, Some(new)) => {
+				incoming.push(new.clone());
+				new_i = new_iter.next();
+			},
+		}
+	}
+	(incoming, outgoing)
+}
+
+/// A hook for when the members of a committee change.
+pub trait OnMembersChanged<DaoId, AccountId> {
+	/// The members `incoming` joined a committee of a DAO and `outgoing` left it, leaving
+	/// `sorted_new`.
+	fn on_members_changed(
+		dao_id: DaoId,
+		committee_id: CommitteeId,
+		incoming: &[AccountId],
+		outgoing: &[AccountId],
+		sorted_new: &[AccountId],
+	);
+}
+
+impl<DaoId, AccountId> OnMembersChanged<DaoId, AccountId> for () {
+	fn on_members_changed(
+		_dao_id: DaoId,
+		_committee_id: CommitteeId,
+		_incoming: &[AccountId],
+		_outgoing: &[AccountId],
+		_sorted_new: &[AccountId],
+	) {
+	}
+}
+
+/// Keep the members of another pallet, such as emergency, in sync with the committee
+/// `Committee` of each DAO.
+pub struct SyncMembers<Target, Committee>(PhantomData<(Target, Committee)>);
+
+impl<DaoId, AccountId, Target, Committee> OnMembersChanged<DaoId, AccountId>
+	for SyncMembers<Target, Committee>
+where
+	DaoId: Clone + Default + Copy,
+	AccountId: Clone + Ord,
+	Target: ChangeMembers<AccountId, DaoId>,
+	Committee: Get<CommitteeId>,
+{
+	fn on_members_changed(
+		dao_id: DaoId,
+		committee_id: CommitteeId,
+		incoming: &[AccountId],
+		outgoing: &[AccountId],
+		sorted_new: &[AccountId],
+	) {
+		if committee_id == Committee::get() {
+			Target::change_members_sorted(dao_id, incoming, outgoing, sorted_new);
+		}
+	}
+}
+
 /// Some sort of check on the origin is performed by this object.
 pub trait EnsureOriginWithArg<OuterOrigin, Argument> {
 	/// A return type.
@@ -32,18 +32,39 @@ pub trait WeightInfo {
     fn execute() -> Weight;
     fn propose() -> Weight;
     fn vote() -> Weight;
+    fn submit_signed_votes() -> Weight;
+    fn abstain() -> Weight;
     fn close() -> Weight;
+    fn delegate() -> Weight;
+    fn undelegate() -> Weight;
+    fn withdraw() -> Weight;
     fn disapprove_proposal() -> Weight;
     fn set_motion_duration() -> Weight;
     fn set_max_proposals() -> Weight;
     fn set_max_members() -> Weight;
     fn set_ensure_origin_for_every_call() -> Weight;
+    fn veto() -> Weight;
+    fn set_veto_holder() -> Weight;
+    fn set_cooling_off_period() -> Weight;
+    fn set_blacklist_period() -> Weight;
+    fn create_committee() -> Weight;
+    fn set_committee_members() -> Weight;
+    fn set_committee_threshold() -> Weight;
+    fn set_committee_ensure_origin() -> Weight;
+    fn set_default_vote_policy() -> Weight;
+    fn set_ensure_origin_tiers() -> Weight;
+    fn set_ensure_origins() -> Weight;
+    fn register_ensure_origin_preset() -> Weight;
+    fn apply_ensure_origin_preset() -> Weight;
+    fn set_allowance() -> Weight;
 }
 
 /// Weights for daos_agency using the Substrate node and recommended hardware.
 pub struct DaosWeight<T>(PhantomData<T>);
         impl<T: frame_system::Config> WeightInfo for DaosWeight<T> {
             // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Allowances (r:1 w:0)
+            // Storage: DaoAgency AllowanceUsageOf (r:1 w:1)
         fn execute() -> Weight {
             Weight::from_all(2000_0000)
         }
@@ -54,20 +75,60 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoAgency ProposalCount (r:1 w:1)
             // Storage: DaoAgency MotionDuration (r:1 w:0)
             // Storage: DaoAgency Voting (r:0 w:1)
+            // Storage: DaoAgency MetadataOf (r:0 w:1)
+            // Storage: DaoAgency Blacklist (r:1 w:1)
+            // Storage: DaoAgency ProposerOf (r:0 w:1)
         fn propose() -> Weight {
             Weight::from_all(2000_0000)
         }
             // Storage: DaoAgency CollectiveMembers (r:1 w:0)
             // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency Delegations (r:1 w:0)
         fn vote() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency SignedVoteNonce (r:1 w:1)
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency Delegations (r:1 w:0)
+        fn submit_signed_votes() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency Delegations (r:1 w:0)
+        fn abstain() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: DaoAgency Voting (r:1 w:1)
             // Storage: DaoAgency CollectiveMembers (r:1 w:0)
             // Storage: DaoAgency ProposalOf (r:1 w:1)
             // Storage: DaoAgency Proposals (r:1 w:1)
+            // Storage: DaoAgency CoolingOff (r:1 w:1)
+            // Storage: DaoAgency VetoHolder (r:1 w:0)
+            // Storage: DaoAgency CoolingOffPeriod (r:1 w:0)
+            // Storage: DaoAgency Delegations (r:1 w:0)
+            // Storage: DaoAgency DefaultVoteOf (r:1 w:0)
         fn close() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Delegations (r:1 w:1)
+        fn delegate() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency Delegations (r:1 w:1)
+        fn undelegate() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency ProposerOf (r:1 w:1)
+            // Storage: DaoAgency Proposals (r:1 w:1)
+            // Storage: DaoAgency Voting (r:0 w:1)
+            // Storage: DaoAgency ProposalOf (r:0 w:1)
+            // Storage: DaoAgency MetadataOf (r:0 w:1)
+            // Storage: DaoAgency CoolingOff (r:0 w:1)
+        fn withdraw() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
             // Storage: DaoAgency Proposals (r:1 w:1)
@@ -96,11 +157,99 @@ pub struct DaosWeight<T>(PhantomData<T>);
         fn set_ensure_origin_for_every_call() -> Weight {
             Weight::from_all(2000_0000)
         }
+            // Storage: DaoAgency VetoHolder (r:1 w:0)
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency CoolingOff (r:1 w:1)
+            // Storage: DaoAgency BlacklistPeriod (r:1 w:0)
+            // Storage: DaoAgency Blacklist (r:0 w:1)
+            // Storage: DaoAgency Proposals (r:1 w:1)
+            // Storage: DaoAgency Voting (r:0 w:1)
+            // Storage: DaoAgency ProposalOf (r:0 w:1)
+            // Storage: DaoAgency MetadataOf (r:0 w:1)
+        fn veto() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency VetoHolder (r:0 w:1)
+        fn set_veto_holder() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency CoolingOffPeriod (r:0 w:1)
+        fn set_cooling_off_period() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency BlacklistPeriod (r:0 w:1)
+        fn set_blacklist_period() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency NextCommitteeId (r:1 w:1)
+            // Storage: DaoAgency Committees (r:0 w:1)
+        fn create_committee() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:0)
+            // Storage: DaoAgency MaxMembers (r:1 w:0)
+            // Storage: DaoAgency Proposals (r:1 w:0)
+            // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency CommitteeMembers (r:1 w:1)
+        fn set_committee_members() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:1)
+        fn set_committee_threshold() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:0)
+            // Storage: DaoAgency CommitteeEnsureOrigins (r:0 w:1)
+        fn set_committee_ensure_origin() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency DefaultVoteOf (r:0 w:1)
+        fn set_default_vote_policy() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:0)
+            // Storage: DaoAgency EnsureOriginTiers (r:0 w:1)
+        fn set_ensure_origin_tiers() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency EnsureOrigins (r:0 w:64)
+        fn set_ensure_origins() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency EnsureOriginPresets (r:0 w:1)
+        fn register_ensure_origin_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency EnsureOriginPresets (r:1 w:0)
+            // Storage: DaoAgency EnsureOrigins (r:0 w:64)
+        fn apply_ensure_origin_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Allowances (r:0 w:1)
+            // Storage: DaoAgency AllowanceUsageOf (r:0 w:1)
+        fn set_allowance() -> Weight {
+            Weight::from_all(2000_0000)
+        }
     }
 
     // For backwards compatibility and tests
     impl WeightInfo for () {
             // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Allowances (r:1 w:0)
+            // Storage: DaoAgency AllowanceUsageOf (r:1 w:1)
         fn execute() -> Weight {
             Weight::from_all(2000_0000)
         }
@@ -111,20 +260,60 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoAgency ProposalCount (r:1 w:1)
             // Storage: DaoAgency MotionDuration (r:1 w:0)
             // Storage: DaoAgency Voting (r:0 w:1)
+            // Storage: DaoAgency MetadataOf (r:0 w:1)
+            // Storage: DaoAgency Blacklist (r:1 w:1)
+            // Storage: DaoAgency ProposerOf (r:0 w:1)
         fn propose() -> Weight {
             Weight::from_all(2000_0000)
         }
             // Storage: DaoAgency CollectiveMembers (r:1 w:0)
             // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency Delegations (r:1 w:0)
         fn vote() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency SignedVoteNonce (r:1 w:1)
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency Delegations (r:1 w:0)
+        fn submit_signed_votes() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency Delegations (r:1 w:0)
+        fn abstain() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: DaoAgency Voting (r:1 w:1)
             // Storage: DaoAgency CollectiveMembers (r:1 w:0)
             // Storage: DaoAgency ProposalOf (r:1 w:1)
             // Storage: DaoAgency Proposals (r:1 w:1)
+            // Storage: DaoAgency CoolingOff (r:1 w:1)
+            // Storage: DaoAgency VetoHolder (r:1 w:0)
+            // Storage: DaoAgency CoolingOffPeriod (r:1 w:0)
+            // Storage: DaoAgency Delegations (r:1 w:0)
+            // Storage: DaoAgency DefaultVoteOf (r:1 w:0)
         fn close() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency Delegations (r:1 w:1)
+        fn delegate() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency Delegations (r:1 w:1)
+        fn undelegate() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency ProposerOf (r:1 w:1)
+            // Storage: DaoAgency Proposals (r:1 w:1)
+            // Storage: DaoAgency Voting (r:0 w:1)
+            // Storage: DaoAgency ProposalOf (r:0 w:1)
+            // Storage: DaoAgency MetadataOf (r:0 w:1)
+            // Storage: DaoAgency CoolingOff (r:0 w:1)
+        fn withdraw() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
             // Storage: DaoAgency Proposals (r:1 w:1)
@@ -153,4 +342,90 @@ pub struct DaosWeight<T>(PhantomData<T>);
         fn set_ensure_origin_for_every_call() -> Weight {
             Weight::from_all(2000_0000)
         }
+            // Storage: DaoAgency VetoHolder (r:1 w:0)
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency CoolingOff (r:1 w:1)
+            // Storage: DaoAgency BlacklistPeriod (r:1 w:0)
+            // Storage: DaoAgency Blacklist (r:0 w:1)
+            // Storage: DaoAgency Proposals (r:1 w:1)
+            // Storage: DaoAgency Voting (r:0 w:1)
+            // Storage: DaoAgency ProposalOf (r:0 w:1)
+            // Storage: DaoAgency MetadataOf (r:0 w:1)
+        fn veto() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency CollectiveMembers (r:1 w:0)
+            // Storage: DaoAgency VetoHolder (r:0 w:1)
+        fn set_veto_holder() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency CoolingOffPeriod (r:0 w:1)
+        fn set_cooling_off_period() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency BlacklistPeriod (r:0 w:1)
+        fn set_blacklist_period() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency NextCommitteeId (r:1 w:1)
+            // Storage: DaoAgency Committees (r:0 w:1)
+        fn create_committee() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:0)
+            // Storage: DaoAgency MaxMembers (r:1 w:0)
+            // Storage: DaoAgency Proposals (r:1 w:0)
+            // Storage: DaoAgency Voting (r:1 w:1)
+            // Storage: DaoAgency CommitteeMembers (r:1 w:1)
+        fn set_committee_members() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:1)
+        fn set_committee_threshold() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:0)
+            // Storage: DaoAgency CommitteeEnsureOrigins (r:0 w:1)
+        fn set_committee_ensure_origin() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency DefaultVoteOf (r:0 w:1)
+        fn set_default_vote_policy() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Committees (r:1 w:0)
+            // Storage: DaoAgency EnsureOriginTiers (r:0 w:1)
+        fn set_ensure_origin_tiers() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency EnsureOrigins (r:0 w:64)
+        fn set_ensure_origins() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoAgency EnsureOriginPresets (r:0 w:1)
+        fn register_ensure_origin_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency EnsureOriginPresets (r:1 w:0)
+            // Storage: DaoAgency EnsureOrigins (r:0 w:64)
+        fn apply_ensure_origin_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAgency Allowances (r:0 w:1)
+            // Storage: DaoAgency AllowanceUsageOf (r:0 w:1)
+        fn set_allowance() -> Weight {
+            Weight::from_all(2000_0000)
+        }
     }
\ No newline at end of file
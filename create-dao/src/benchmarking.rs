@@ -4,6 +4,10 @@ use crate::{Config, Pallet as Dao};
 use frame_benchmarking::{
 	account, benchmarks, benchmarks_instance, impl_benchmark_test_suite, whitelisted_caller,
 };
+use frame_support::{
+	sp_runtime::traits::{Bounded, Hash},
+	traits::{Currency, Get},
+};
 use frame_system::RawOrigin as SystemOrigin;
 use primitives::AccountIdConversion;
 
@@ -15,6 +19,11 @@ fn get_dao_account<T: Config>(second_id: T::ConcreteId) -> T::AccountId {
 	second_id.into_account()
 }
 
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = PreimageBalanceOf::<T>::max_value() / 2u32.into();
+	T::PreimageCurrency::make_free_balance_be(who, amount);
+}
+
 fn creat_dao<T: Config>() -> (T::DaoId, T::ConcreteId) {
 	let alice = get_alice::<T>();
 	let dao_id = T::DaoId::default();
@@ -38,4 +47,28 @@ benchmarks! {
 		let dao_account = get_dao_account::<T>(second_id);
 		let remark = vec![1; 50];
 	}:_(SystemOrigin::Signed(dao_account), dao_id, remark)
+
+	note_preimage {
+		let (dao_id, _) = creat_dao::<T>();
+		let alice = get_alice::<T>();
+		fund::<T>(&alice);
+		let bytes = vec![1; T::MaxPreimageLen::get() as usize];
+		let hash = T::Hashing::hash(&bytes[..]);
+	}:_(SystemOrigin::Signed(alice), dao_id, bytes)
+	verify {
+		assert!(Dao::<T>::preimage_for(dao_id, hash).is_some());
+	}
+
+	unnote_preimage {
+		let (dao_id, _) = creat_dao::<T>();
+		let alice = get_alice::<T>();
+		fund::<T>(&alice);
+		let bytes = vec![1; T::MaxPreimageLen::get() as usize];
+		let hash = T::Hashing::hash(&bytes[..]);
+		let origin = SystemOrigin::Signed(alice.clone()).into();
+		assert!(Dao::<T>::note_preimage(origin, dao_id, bytes).is_ok());
+	}:_(SystemOrigin::Signed(alice), dao_id, hash)
+	verify {
+		assert!(Dao::<T>::preimage_for(dao_id, hash).is_none());
+	}
 }
@@ -31,21 +31,24 @@
 //! * [Vault Module](https://github.com/DICO-TEAM/dico-chain/blob/main/pallets/vc/src/lib.rs)
 //! * [How to use daos for Vault Module](https://github.com/DICO-TEAM/dico-chain/blob/main/runtime/tico/src/vc.rs)
 
-pub use codec::{MaxEncodedLen, Decode, Encode};
+pub use codec::{MaxEncodedLen, Decode, DecodeLimit, Encode};
 pub use frame_support::{
 	// codec::{Decode, Encode},
 	traits::IsSubType,
 };
+use frame_support::traits::{Currency, ReservableCurrency};
 use frame_support::{sp_runtime::traits::BlockNumberProvider, };
 pub use pallet::*;
 pub use primitives::{
-	traits::{AfterCreate, BaseCallFilter, TryCreate},
-	types::RealCallId,
+	constant::MAX_PREIMAGE_DECODE_DEPTH,
+	traits::{AfterCreate, BaseCallFilter, CallMagnitude, TryCreate},
+	types::{PreimageRef, RealCallId},
 	AccountIdConversion,
 };
 pub use scale_info::{prelude::boxed::Box, TypeInfo};
 // use sp_runtime::traits::BlockNumberProvider;
 pub use sp_runtime::{traits::Hash, RuntimeDebug};
+use sp_runtime::SaturatedConversion;
 pub use sp_std::{
 	marker::PhantomData,
 	prelude::{self, *},
@@ -91,12 +94,18 @@ pub struct DaoInfo<AccountId, BlockNumber, ConcreteId, Status> {
 pub mod pallet {
 	use super::*;
 	use frame_support::{
-		dispatch::DispatchResultWithPostInfo, pallet_prelude::*, traits::UnfilteredDispatchable,
-		// weights::GetDispatchInfo,
+		dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
+		pallet_prelude::*,
+		traits::UnfilteredDispatchable,
 	};
 	use frame_system::pallet_prelude::*;
+	use frame_support::sp_runtime::traits::{Hash, Saturating};
 	use sp_runtime::traits::{CheckedAdd, One};
 
+	pub type PreimageBalanceOf<T> = <<T as Config>::PreimageCurrency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -106,7 +115,7 @@ pub mod pallet {
 		/// All calls supported by DAO.
 		type Call: Parameter
 			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
-			// + GetDispatchInfo
+			+ GetDispatchInfo
 			+ From<frame_system::Call<Self>>
 			+ From<Call<Self>>
 			+ IsSubType<Call<Self>>
@@ -139,6 +148,24 @@ pub mod pallet {
 		/// Do some things after creating dao, such as setting up a sudo account.
 		type AfterCreate: AfterCreate<Self::AccountId, Self::DaoId>;
 
+		/// Pull a numeric magnitude out of a call, so that its permissions can depend on it.
+		type CallMagnitude: CallMagnitude<<Self as pallet::Config>::Call>;
+
+		/// The currency in which the deposit of a preimage is reserved.
+		type PreimageCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit of noting a preimage, on top of the deposit per byte.
+		#[pallet::constant]
+		type PreimageBaseDeposit: Get<PreimageBalanceOf<Self>>;
+
+		/// The deposit of noting a preimage for each byte of the call.
+		#[pallet::constant]
+		type PreimageByteDeposit: Get<PreimageBalanceOf<Self>>;
+
+		/// The maximum length of an encoded call in the preimage store.
+		#[pallet::constant]
+		type MaxPreimageLen: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -163,11 +190,45 @@ pub mod pallet {
 	#[pallet::getter(fn next_dao_id)]
 	pub type NextDaoId<T: Config> = StorageValue<_, T::DaoId, ValueQuery>;
 
+	/// The encoded calls that the proposals of a DAO refer to by hash.
+	#[pallet::storage]
+	#[pallet::getter(fn preimage_for)]
+	pub type PreimageFor<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		T::Hash,
+		BoundedVec<u8, T::MaxPreimageLen>,
+	>;
+
+	/// Who noted a preimage and the deposit they reserved for it.
+	#[pallet::storage]
+	#[pallet::getter(fn preimage_deposit_of)]
+	pub type PreimageDepositOf<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		T::Hash,
+		(T::AccountId, PreimageBalanceOf<T>),
+	>;
+
+	/// How many proposals of other pallets refer to a preimage.
+	#[pallet::storage]
+	#[pallet::getter(fn preimage_ref_count)]
+	pub type PreimageRefCount<T: Config> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, T::Hash, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// The new DAO is successfully created.
 		CreatedDao(T::AccountId, T::DaoId, T::ConcreteId),
+		/// A preimage was noted in a DAO.
+		PreimageNoted(T::AccountId, T::DaoId, T::Hash),
+		/// A preimage was removed from a DAO and its deposit returned.
+		PreimageUnnoted(T::AccountId, T::DaoId, T::Hash),
 	}
 
 	#[pallet::error]
@@ -188,6 +249,18 @@ pub mod pallet {
 		DescribeTooLong,
 		/// Numerical calculation overflow error.
 		Overflow,
+		/// The preimage is longer than `MaxPreimageLen`.
+		PreimageTooLarge,
+		/// The preimage has already been noted.
+		PreimageAlreadyNoted,
+		/// The preimage has not been noted, or has a different length.
+		PreimageMissing,
+		/// The preimage does not decode to a call.
+		PreimageUndecodable,
+		/// Only the account that noted the preimage can unnote it.
+		NotPreimageDepositor,
+		/// Some proposals still refer to the preimage.
+		PreimageInUse,
 	}
 
 	#[pallet::call]
@@ -204,18 +277,17 @@ pub mod pallet {
 			ensure!(describe.len() <= 50, Error::<T>::DescribeTooLong);
 			let dao_id = NextDaoId::<T>::get();
 
-			if !cfg!(any(feature = "std", feature = "runtime-benchmarks")) {
+			if !cfg!(feature = "runtime-benchmarks") {
 				concrete_id.try_create(creator.clone(), dao_id)?;
 			}
 
-			// fixme
 			let now = frame_system::Pallet::<T>::current_block_number();
 
 			Daos::<T>::insert(
 				dao_id,
 				DaoInfo {
 					creator: creator.clone(),
-					start_block: 100,
+					start_block: now.saturated_into(),
 					concrete_id,
 					// describe,
 					status: Status::Active,
@@ -241,6 +313,61 @@ pub mod pallet {
 			Self::ensrue_dao_root(origin, dao_id)?;
 			Ok(().into())
 		}
+
+		/// Note the encoding of a call in a DAO, so that proposals can refer to it by hash.
+		///
+		/// A deposit of `PreimageBaseDeposit` plus `PreimageByteDeposit` per byte is reserved.
+		#[pallet::weight(T::WeightInfo::note_preimage())]
+		pub fn note_preimage(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			bytes: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Daos::<T>::contains_key(dao_id), Error::<T>::DaoNotExists);
+			let hash = T::Hashing::hash(&bytes[..]);
+			ensure!(
+				!PreimageFor::<T>::contains_key(dao_id, hash),
+				Error::<T>::PreimageAlreadyNoted
+			);
+			let len = bytes.len() as u32;
+			let bytes: BoundedVec<u8, T::MaxPreimageLen> =
+				bytes.try_into().map_err(|_| Error::<T>::PreimageTooLarge)?;
+
+			let deposit = T::PreimageBaseDeposit::get()
+				.saturating_add(T::PreimageByteDeposit::get().saturating_mul(len.into()));
+			T::PreimageCurrency::reserve(&who, deposit)?;
+
+			PreimageFor::<T>::insert(dao_id, hash, bytes);
+			PreimageDepositOf::<T>::insert(dao_id, hash, (who.clone(), deposit));
+			Self::deposit_event(Event::PreimageNoted(who, dao_id, hash));
+			Ok(().into())
+		}
+
+		/// Remove a preimage noted by the caller and return its deposit.
+		///
+		/// A preimage can not be removed while some proposals still refer to it.
+		#[pallet::weight(T::WeightInfo::unnote_preimage())]
+		pub fn unnote_preimage(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let (depositor, deposit) =
+				PreimageDepositOf::<T>::get(dao_id, hash).ok_or(Error::<T>::PreimageMissing)?;
+			ensure!(depositor == who, Error::<T>::NotPreimageDepositor);
+			ensure!(
+				PreimageRefCount::<T>::get(dao_id, hash) == 0,
+				Error::<T>::PreimageInUse
+			);
+
+			T::PreimageCurrency::unreserve(&who, deposit);
+			PreimageFor::<T>::remove(dao_id, hash);
+			PreimageDepositOf::<T>::remove(dao_id, hash);
+			Self::deposit_event(Event::PreimageUnnoted(who, dao_id, hash));
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -281,5 +408,46 @@ pub mod pallet {
 			ensure!(who == dao_id, Error::<T>::BadOrigin);
 			Ok(who)
 		}
+
+		/// Make sure that a preimage of this length has been noted in the DAO.
+		pub fn ensure_preimage(
+			dao_id: T::DaoId,
+			preimage: &PreimageRef<T::Hash>,
+		) -> result::Result<(), DispatchError> {
+			let len = PreimageFor::<T>::decode_len(dao_id, preimage.hash);
+			ensure!(len == Some(preimage.len as usize), Error::<T>::PreimageMissing);
+			Ok(())
+		}
+
+		/// Make sure that a preimage has been noted, and keep it until `unrequest_preimage`.
+		pub fn request_preimage(
+			dao_id: T::DaoId,
+			preimage: &PreimageRef<T::Hash>,
+		) -> result::Result<(), DispatchError> {
+			Self::ensure_preimage(dao_id, preimage)?;
+			PreimageRefCount::<T>::mutate(dao_id, preimage.hash, |count| {
+				*count = count.saturating_add(1)
+			});
+			Ok(())
+		}
+
+		/// Drop a reference taken by `request_preimage`.
+		pub fn unrequest_preimage(dao_id: T::DaoId, hash: T::Hash) {
+			PreimageRefCount::<T>::mutate_exists(dao_id, hash, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+		}
+
+		/// Fetch the call that a preimage refers to and decode it.
+		pub fn try_get_preimage_call<C: Decode>(
+			dao_id: T::DaoId,
+			preimage: &PreimageRef<T::Hash>,
+		) -> result::Result<C, DispatchError> {
+			let bytes = PreimageFor::<T>::get(dao_id, preimage.hash)
+				.filter(|b| b.len() == preimage.len as usize)
+				.ok_or(Error::<T>::PreimageMissing)?;
+			C::decode_all_with_depth_limit(MAX_PREIMAGE_DECODE_DEPTH, &mut &bytes[..])
+				.map_err(|_| Error::<T>::PreimageUndecodable.into())
+		}
 	}
 }
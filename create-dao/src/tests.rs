@@ -1,9 +1,11 @@
 #![allow(unused_imports)]
 #![cfg(test)]
 use super::*;
-use crate::mock::*;
+use crate::mock::{Call, Origin, *};
 use frame_support::{assert_noop, assert_ok, debug, log::debug};
+use frame_support::traits::{Currency, ReservableCurrency};
 use primitives::ids::Nft;
+use sp_runtime::traits::BlakeTwo256;
 
 pub const ALICE: u64 = 1;
 
@@ -69,3 +71,66 @@ pub fn get_dao_account_id() {
 		assert_ok!(Pallet::<Test>::try_get_dao_account_id(0u64));
 	});
 }
+
+#[test]
+pub fn preimage_should_work() {
+	new_test_ext().execute_with(|| {
+		let call = Call::DAO(crate::Call::dao_remark { dao_id: 0u64, remark: vec![1; 10] });
+		let bytes = call.encode();
+		let preimage = PreimageRef { hash: BlakeTwo256::hash(&bytes[..]), len: bytes.len() as u32 };
+		assert_noop!(
+			Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, bytes.clone()),
+			Error::<Test>::DaoNotExists
+		);
+		create_dao();
+		assert!(Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, bytes.clone()).is_err());
+
+		let _ = Balances::deposit_creating(&ALICE, 100);
+		assert_ok!(Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, bytes.clone()));
+		assert_eq!(Balances::reserved_balance(ALICE), 1 + bytes.len() as u64);
+		assert_noop!(
+			Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, bytes.clone()),
+			Error::<Test>::PreimageAlreadyNoted
+		);
+		assert_noop!(
+			Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, vec![1; 1025]),
+			Error::<Test>::PreimageTooLarge
+		);
+
+		assert_ok!(Pallet::<Test>::ensure_preimage(0u64, &preimage));
+		let wrong_len = PreimageRef { len: preimage.len + 1, ..preimage };
+		assert!(Pallet::<Test>::ensure_preimage(0u64, &wrong_len).is_err());
+		assert_eq!(Pallet::<Test>::try_get_preimage_call::<Call>(0u64, &preimage), Ok(call));
+
+		let garbage = vec![255u8; 4];
+		let undecodable = PreimageRef { hash: BlakeTwo256::hash(&garbage[..]), len: 4 };
+		assert_ok!(Pallet::<Test>::note_preimage(Origin::signed(ALICE), 0u64, garbage));
+		assert_eq!(
+			Pallet::<Test>::try_get_preimage_call::<Call>(0u64, &undecodable),
+			Err(Error::<Test>::PreimageUndecodable.into())
+		);
+
+		assert_noop!(
+			Pallet::<Test>::unnote_preimage(Origin::signed(2u64), 0u64, preimage.hash),
+			Error::<Test>::NotPreimageDepositor
+		);
+		// a preimage stays while proposals refer to it.
+		assert_ok!(Pallet::<Test>::request_preimage(0u64, &preimage));
+		assert_ok!(Pallet::<Test>::request_preimage(0u64, &preimage));
+		assert!(Pallet::<Test>::request_preimage(0u64, &wrong_len).is_err());
+		assert_eq!(Pallet::<Test>::preimage_ref_count(0u64, preimage.hash), 2);
+		Pallet::<Test>::unrequest_preimage(0u64, preimage.hash);
+		assert_noop!(
+			Pallet::<Test>::unnote_preimage(Origin::signed(ALICE), 0u64, preimage.hash),
+			Error::<Test>::PreimageInUse
+		);
+		Pallet::<Test>::unrequest_preimage(0u64, preimage.hash);
+		assert!(!crate::PreimageRefCount::<Test>::contains_key(0u64, preimage.hash));
+		assert_ok!(Pallet::<Test>::unnote_preimage(Origin::signed(ALICE), 0u64, preimage.hash));
+		assert_eq!(Balances::reserved_balance(ALICE), 5);
+		assert_eq!(
+			Pallet::<Test>::try_get_preimage_call::<Call>(0u64, &preimage),
+			Err(Error::<Test>::PreimageMissing.into())
+		);
+	});
+}
@@ -31,6 +31,8 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn create_dao() -> Weight;
     fn dao_remark() -> Weight;
+    fn note_preimage() -> Weight;
+    fn unnote_preimage() -> Weight;
 }
 
 /// Weights for daos_create_dao using the Substrate node and recommended hardware.
@@ -51,6 +53,20 @@ pub struct DaosWeight<T>(PhantomData<T>);
         // (11_086_000 as Weight)
         //     .saturating_add(T::DbWeight::get().reads(1 as Weight))
         }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: CreateDao PreimageFor (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: CreateDao PreimageDepositOf (r:0 w:1)
+        fn note_preimage() -> Weight {
+                Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao PreimageDepositOf (r:1 w:1)
+            // Storage: CreateDao PreimageRefCount (r:1 w:0)
+            // Storage: System Account (r:1 w:1)
+            // Storage: CreateDao PreimageFor (r:0 w:1)
+        fn unnote_preimage() -> Weight {
+                Weight::from_all(2000_0000)
+        }
     }
 
     // For backwards compatibility and tests
@@ -70,4 +86,18 @@ pub struct DaosWeight<T>(PhantomData<T>);
         // (11_086_000 as Weight)
         //     .saturating_add(RocksDbWeight::get().reads(1 as Weight))
         }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: CreateDao PreimageFor (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: CreateDao PreimageDepositOf (r:0 w:1)
+        fn note_preimage() -> Weight {
+                Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao PreimageDepositOf (r:1 w:1)
+            // Storage: CreateDao PreimageRefCount (r:1 w:0)
+            // Storage: System Account (r:1 w:1)
+            // Storage: CreateDao PreimageFor (r:0 w:1)
+        fn unnote_preimage() -> Weight {
+                Weight::from_all(2000_0000)
+        }
     }
\ No newline at end of file
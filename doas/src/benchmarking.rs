@@ -38,7 +38,7 @@ benchmarks! {
 		let (dao_id, _second_id) = creat_dao::<T>();
 		let call = get_call::<T>(dao_id);
 		let call_id: T::CallId= TryFrom::<<T as dao::Config>::Call>::try_from(call.clone()).map_err(|_| "no call id")?;
-	}:  _<T::Origin>(T::DoAsOrigin::successful_origin(&(dao_id, call_id)), dao_id, Box::new(call))
+	}:  _<T::Origin>(T::DoAsOrigin::successful_origin(&(dao_id, 0, call_id, None)), dao_id, 0, Box::new(call))
 	verify {
 
 	}
@@ -22,6 +22,9 @@
 //! The agency must go through the DoAs module `do_as_agency` to `execute` or `propose` external transactions.
 //! In other words, the proposal in the Agency module must be `do_as_agency`.
 //!
+//! `do_as_agency` names the committee of the agency that executes the call,
+//! and only succeeds if the origin comes from that committee and meets its origin for the call.
+//!
 //! Below is a test code case that agency calls the `set_max_members` method.
 //! ***
 //! let set_max_members =
@@ -29,12 +32,14 @@
 //!
 //! let do_as_agency = Call::DoAs(daos_doas::Call::do_as_agency {
 //! 			dao_id: 0u64,
+//! 			committee_id: 0u32,
 //! 			call: Box::new(set_max_members),
 //! 		});
 //!
 //! assert!(crate::Pallet::<Test>::execute(
 //! 			Origin::signed(ALICE),
 //! 			0u64,
+//! 			0u32,
 //! 			Box::new(do_as_agency)
 //! 		)
 //! 		.is_ok());
@@ -49,8 +54,8 @@ pub use frame_support::{
 };
 pub use pallet::*;
 pub use primitives::{
-	traits::EnsureOriginWithArg,
-	types::{DoAsEnsureOrigin, MemberCount, Proportion, RealCallId},
+	traits::{CallMagnitude, EnsureOriginWithArg},
+	types::{CommitteeId, DoAsEnsureOrigin, MemberCount, Proportion, RealCallId},
 	AccountIdConversion,
 };
 pub use scale_info::{prelude::boxed::Box, TypeInfo};
@@ -80,10 +85,12 @@ pub mod pallet {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		/// Origin must be from collective.
+		/// Origin must be from the given committee of the collective.
+		///
+		/// The argument also carries the magnitude of the call, if it has one.
 		type DoAsOrigin: EnsureOriginWithArg<
 			Self::RuntimeOrigin,
-			(Self::DaoId, Self::CallId),
+			(Self::DaoId, CommitteeId, Self::CallId, Option<u128>),
 			Success = Self::DaoId,
 		>;
 
@@ -108,11 +115,12 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// The agency execute an external call
+		/// A committee of the agency execute an external call
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::do_as_agency())]
 		pub fn do_as_agency(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
+			committee_id: CommitteeId,
 			call: Box<<T as dao::Config>::Call>,
 		) -> DispatchResultWithPostInfo {
 			ensure!(
@@ -122,7 +130,8 @@ pub mod pallet {
 			let call_id: T::CallId =
 				TryFrom::<<T as dao::Config>::Call>::try_from(*call.clone()).unwrap_or_default();
 
-			let id = T::DoAsOrigin::try_origin(origin, &(dao_id, call_id))
+			let magnitude = <T as dao::Config>::CallMagnitude::magnitude(&call);
+			let id = T::DoAsOrigin::try_origin(origin, &(dao_id, committee_id, call_id, magnitude))
 				.map_err(|_| dao::Error::<T>::BadOrigin)?;
 			ensure!(dao_id == id, dao::Error::<T>::DaoIdNotMatch);
 			let dao_account = dao::Pallet::<T>::try_get_dao_account_id(dao_id)?;
@@ -6,6 +6,7 @@ use dao::Call as DaoCall;
 use frame_benchmarking::{
 	account, benchmarks, benchmarks_instance, impl_benchmark_test_suite, whitelisted_caller,
 };
+use frame_support::sp_runtime::traits::{Bounded, Hash};
 use frame_system::RawOrigin as SystemOrigin;
 use primitives::AccountIdConversion;
 use sp_runtime::SaturatedConversion;
@@ -44,10 +45,17 @@ fn creat_dao<T: Config>() -> (T::DaoId, T::ConcreteId) {
 	(dao_id, second_id)
 }
 
-fn get_call<T: Config>(dao_id: T::DaoId) -> (<T as dao::Config>::Call, T::Hash) {
+fn get_call<T: Config>(dao_id: T::DaoId) -> (PreimageRef<T::Hash>, T::Hash) {
 	let proposal: <T as dao::Config>::Call =
 		DaoCall::<T>::dao_remark { dao_id, remark: vec![1; 20] }.into();
-	(proposal.clone(), T::Hashing::hash_of(&proposal))
+	let bytes = proposal.encode();
+	let preimage = PreimageRef { hash: T::Hashing::hash(&bytes[..]), len: bytes.len() as u32 };
+	let alice = get_alice::<T>();
+	let amount = dao::PreimageBalanceOf::<T>::max_value() / 2u32.into();
+	T::PreimageCurrency::make_free_balance_be(&alice, amount);
+	let origin = SystemOrigin::Signed(alice).into();
+	assert!(dao::Pallet::<T>::note_preimage(origin, dao_id, bytes).is_ok());
+	(preimage, preimage.hash)
 }
 
 fn get_members<T: Config>() -> T::DaoId {
@@ -82,7 +90,7 @@ fn internal<T: Config>() -> (T::DaoId, T::Hash) {
 	assert!(Emergency::<T>::internal_track(
 		SystemOrigin::Signed(get_alice::<T>()).into(),
 		dao_id,
-		Box::new(proposal),
+		proposal,
 		vec![1, 2, 3, 4]
 	)
 	.is_ok());
@@ -104,12 +112,12 @@ benchmarks! {
 	external_track {
 		let (dao_id, second_id) = creat_dao::<T>();
 		let (proposal, hash) = get_call::<T>(dao_id);
-	}:_(SystemOrigin::Root, dao_id, Box::new(proposal), vec![1, 2, 3, 4])
+	}:_(SystemOrigin::Root, dao_id, proposal, vec![1, 2, 3, 4])
 
 	internal_track {
 		let dao_id = get_members::<T>();
 		let (proposal, hash) = get_call::<T>(dao_id);
-	}:_(SystemOrigin::Signed(get_alice::<T>()), dao_id, Box::new(proposal), vec![1, 2, 3, 4])
+	}:_(SystemOrigin::Signed(get_alice::<T>()), dao_id, proposal, vec![1, 2, 3, 4])
 
 	reject {
 		let (dao_id, hash) = internal::<T>();
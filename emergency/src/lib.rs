@@ -28,12 +28,11 @@
 //! Anyone can reject internal proposals.
 //!
 
-use dao::{Box, Vec};
+use dao::{PreimageRef, Vec};
 use codec::{Decode, Encode};
 use frame_support::traits::UnfilteredDispatchable;
-use sp_runtime::traits::Hash;
 use frame_support::{
-	dispatch::{DispatchResultWithPostInfo},
+	dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
 	pallet_prelude::*,
 	traits::{Currency, ReservableCurrency},
 	transactional,
@@ -43,7 +42,7 @@ pub use pallet::*;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{BlockNumberProvider, CheckedAdd},
-	RuntimeDebug,
+	RuntimeDebug, SaturatedConversion,
 };
 use weights::WeightInfo;
 
@@ -57,13 +56,13 @@ mod weights;
 
 /// Specific information on emergency proposal.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
-pub struct ProposalInfo<AccountId, Call, Amount, BlockNumber> {
+pub struct ProposalInfo<AccountId, Proposal, Amount, BlockNumber> {
 	/// who initiated the emergency proposal.
 	who: Option<AccountId>,
 	/// Proposal end block height.
 	end_block: BlockNumber,
-	/// proposal.
-	call: Call,
+	/// The preimage of the proposal.
+	call: Proposal,
 	/// The amount that the proposal needs to pledge.
 	pledge: Amount,
 	/// Reason for Proposal.
@@ -93,6 +92,9 @@ pub mod pallet {
 		/// How long the proposal takes.
 		#[pallet::constant]
 		type TrackPeriod: Get<u32>;
+		/// The maximum number of emergency proposals enacted automatically in a block.
+		#[pallet::constant]
+		type MaxEnactmentsPerBlock: Get<u32>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -125,16 +127,75 @@ pub mod pallet {
 		T::DaoId,
 		Identity,
 		T::Hash,
-		ProposalInfo<T::AccountId, <T as dao::Config>::Call, BalanceOf<T>, u32>,
+		ProposalInfo<T::AccountId, PreimageRef<T::Hash>, BalanceOf<T>, u32>,
 	>;
 
+	/// Emergency proposals enacted automatically at a given block.
+	#[pallet::storage]
+	#[pallet::getter(fn enactments_due)]
+	pub type EnactmentsDue<T: Config> =
+		StorageMap<_, Identity, u32, Vec<(T::DaoId, T::Hash)>, ValueQuery>;
+
+	/// Due emergency proposals over the per block limit, waiting to be enacted.
+	#[pallet::storage]
+	#[pallet::getter(fn enactments_overdue)]
+	pub type EnactmentsOverdue<T: Config> = StorageValue<_, Vec<(T::DaoId, T::Hash)>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Enact the overdue emergency proposals and then the ones that are due, at most
+		/// `MaxEnactmentsPerBlock` of them.
+		///
+		/// The rest waits in `EnactmentsOverdue`.
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let now: u32 = n.saturated_into();
+			let mut due = EnactmentsOverdue::<T>::take();
+			due.append(&mut EnactmentsDue::<T>::take(now));
+			let weight = T::DbWeight::get().reads_writes(2, 2);
+			let limit = T::MaxEnactmentsPerBlock::get() as usize;
+			if due.len() > limit {
+				EnactmentsOverdue::<T>::put(due.split_off(limit));
+			}
+			due.into_iter().fold(weight, |weight, (dao_id, proposal_hash)| {
+				let executed = Self::enact_due(dao_id, proposal_hash);
+				weight
+					.saturating_add(<T as Config>::WeightInfo::enact_proposal())
+					.saturating_add(executed)
+			})
+		}
+
+		/// Enact overdue emergency proposals while the weight left in the block covers them and
+		/// their calls.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut overdue = EnactmentsOverdue::<T>::take();
+			let mut enacted = 0;
+			for (dao_id, proposal_hash) in overdue.iter().copied() {
+				let enact = weight.saturating_add(<T as Config>::WeightInfo::enact_proposal());
+				let call = ProposalOf::<T>::get(dao_id, proposal_hash)
+					.map(|p| Self::call_weight(dao_id, &p.call))
+					.unwrap_or_else(Weight::zero);
+				if enact.saturating_add(call).any_gt(remaining_weight) {
+					break
+				}
+				weight = enact.saturating_add(Self::enact_due(dao_id, proposal_hash));
+				enacted += 1;
+			}
+			overdue.drain(..enacted);
+			if !overdue.is_empty() {
+				EnactmentsOverdue::<T>::put(overdue);
+			}
+			weight
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Set members who can make emergency proposals.
 		SetMembers { dao_id: T::DaoId, members: Vec<T::AccountId> },
 		/// Successfully made an emergency proposal.
-		Track { dao_id: T::DaoId, who: Option<T::AccountId>, call: <T as dao::Config>::Call },
+		Track { dao_id: T::DaoId, who: Option<T::AccountId>, call: PreimageRef<T::Hash> },
 		/// Successfully rejected an emergency proposal.
 		Rejected { dao_id: T::DaoId, proposal_hash: T::Hash },
 		/// Execute a transaction related to an emergency proposal.
@@ -193,11 +254,11 @@ pub mod pallet {
 		pub fn external_track(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
-			proposal: Box<<T as dao::Config>::Call>,
+			proposal: PreimageRef<T::Hash>,
 			reason: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			T::ExternalOrigin::ensure_origin(origin)?;
-			Self::try_propose(dao_id, *proposal, None, reason)
+			Self::try_propose(dao_id, proposal, None, reason)
 		}
 
 		/// Member initiates an urgent proposal.
@@ -206,13 +267,13 @@ pub mod pallet {
 		pub fn internal_track(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
-			proposal: Box<<T as dao::Config>::Call>,
+			proposal: PreimageRef<T::Hash>,
 			reason: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			ensure!(Members::<T>::get(dao_id).contains(&who), Error::<T>::NotEmergencyMembers);
-			Self::try_propose(dao_id, *proposal, Some(who), reason)
+			Self::try_propose(dao_id, proposal, Some(who), reason)
 		}
 
 		/// Rejected an emergency proposal.
@@ -241,6 +302,7 @@ pub mod pallet {
 				if let Some(who) = proposal.who.clone() {
 					T::Currency::slash_reserved(&who, proposal.pledge);
 				}
+				dao::Pallet::<T>::unrequest_preimage(dao_id, proposal_hash);
 				Self::deposit_event(Event::Rejected { dao_id, proposal_hash });
 				Ok(().into())
 			})
@@ -255,43 +317,84 @@ pub mod pallet {
 			proposal_hash: T::Hash,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
-			HashesOf::<T>::try_mutate(dao_id, |hashes| -> DispatchResultWithPostInfo {
-				hashes.retain(|h| h != &proposal_hash);
-				let proposal = ProposalOf::<T>::take(dao_id, proposal_hash)
+			Self::do_enact_proposal(dao_id, proposal_hash)?;
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn now() -> u32 {
+			frame_system::Pallet::<T>::current_block_number().saturated_into()
+		}
+
+		/// Enact an emergency proposal, returning the weight of the call it dispatched.
+		fn do_enact_proposal(
+			dao_id: T::DaoId,
+			proposal_hash: T::Hash,
+		) -> Result<Weight, DispatchError> {
+			HashesOf::<T>::try_mutate(dao_id, |hashes| -> Result<Weight, DispatchError> {
+				// an enacted proposal is kept in `ProposalOf`, but can not be enacted again.
+				ensure!(hashes.contains(&proposal_hash), Error::<T>::ProposalNotExists);
+				let proposal = ProposalOf::<T>::get(dao_id, proposal_hash)
 					.ok_or(Error::<T>::ProposalNotExists)?;
 				ensure!(Self::now() >= proposal.end_block, Error::<T>::ProposalNotEnd);
-				if let Some(who) = proposal.who.clone() {
+				hashes.retain(|h| h != &proposal_hash);
+				if let Some(who) = proposal.who {
 					T::Currency::unreserve(&who, proposal.pledge);
 				}
-				ProposalOf::<T>::insert(dao_id, proposal_hash, proposal.clone());
-				let res = proposal.call.dispatch_bypass_filter(
-					frame_system::RawOrigin::Signed(dao::Pallet::<T>::try_get_dao_account_id(
-						dao_id,
-					)?)
-					.into(),
-				);
+				// a proposal whose call can not be fetched is enacted as failed.
+				let declared = Self::call_weight(dao_id, &proposal.call);
+				let res = Self::dispatch_as_dao(dao_id, &proposal.call);
+				let actual = match res {
+					Ok(info) => info.actual_weight,
+					Err(e) => e.post_info.actual_weight,
+				};
+				dao::Pallet::<T>::unrequest_preimage(dao_id, proposal_hash);
 
 				Self::deposit_event(Event::EnactProposal { dao_id, proposal_hash, res });
-				Ok(().into())
+				Ok(actual.unwrap_or(declared))
 			})
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		// fixme
-		fn now() -> u32 {
+		/// The declared weight of the call of a preimage, zero if it can not be read.
+		fn call_weight(dao_id: T::DaoId, call: &PreimageRef<T::Hash>) -> Weight {
+			dao::Pallet::<T>::try_get_preimage_call::<<T as dao::Config>::Call>(dao_id, call)
+				.map(|call| call.get_dispatch_info().weight)
+				.unwrap_or_else(|_| Weight::zero())
+		}
+
+		fn dispatch_as_dao(
+			dao_id: T::DaoId,
+			call: &PreimageRef<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			let call: <T as dao::Config>::Call =
+				dao::Pallet::<T>::try_get_preimage_call(dao_id, call)?;
+			let dao_account = dao::Pallet::<T>::try_get_dao_account_id(dao_id)?;
+			call.dispatch_bypass_filter(frame_system::RawOrigin::Signed(dao_account).into())
+		}
 
-			// frame_system::Pallet::<T>::current_block_number()
-			100
+		/// Enact an emergency proposal that is due, unless it was rejected or enacted already.
+		///
+		/// Returns the weight of the call it dispatched, if any.
+		fn enact_due(dao_id: T::DaoId, proposal_hash: T::Hash) -> Weight {
+			if !HashesOf::<T>::get(dao_id).contains(&proposal_hash) {
+				return Weight::zero()
+			}
+			Self::do_enact_proposal(dao_id, proposal_hash).unwrap_or_else(|_| {
+				// not over yet, try again in the next block.
+				EnactmentsDue::<T>::append(Self::now().saturating_add(1), (dao_id, proposal_hash));
+				Weight::zero()
+			})
 		}
 
 		fn try_propose(
 			dao_id: T::DaoId,
-			proposal: <T as dao::Config>::Call,
+			proposal: PreimageRef<T::Hash>,
 			who: Option<T::AccountId>,
 			reason: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
-			let proposal_hash: T::Hash = T::Hashing::hash_of(&proposal);
+			dao::Pallet::<T>::ensure_preimage(dao_id, &proposal)?;
+			let proposal_hash = proposal.hash;
 			HashesOf::<T>::try_mutate(dao_id, |hashes| -> DispatchResultWithPostInfo {
 				if !hashes.contains(&proposal_hash) {
 					hashes.push(proposal_hash);
@@ -306,13 +409,15 @@ pub mod pallet {
 					if let Some(w) = who.clone() {
 						T::Currency::reserve(&w, pledge)?;
 					}
+					EnactmentsDue::<T>::append(end_block, (dao_id, proposal_hash));
+					dao::Pallet::<T>::request_preimage(dao_id, &proposal)?;
 					ProposalOf::<T>::insert(
 						dao_id,
 						proposal_hash,
 						&ProposalInfo {
 							who: who.clone(),
 							end_block,
-							call: proposal.clone(),
+							call: proposal,
 							pledge,
 							reason,
 						},
@@ -325,3 +430,16 @@ pub mod pallet {
 		}
 	}
 }
+
+impl<T: Config> daos_agency::traits::ChangeMembers<T::AccountId, T::DaoId> for Pallet<T> {
+	fn change_members_sorted(
+		dao_id: T::DaoId,
+		_incoming: &[T::AccountId],
+		_outgoing: &[T::AccountId],
+		sorted_new: &[T::AccountId],
+	) {
+		let members = sorted_new.to_vec();
+		Members::<T>::insert(dao_id, &members);
+		Self::deposit_event(Event::SetMembers { dao_id, members });
+	}
+}
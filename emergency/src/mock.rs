@@ -96,6 +96,11 @@ impl dao::Config for Test {
 	type DaoId = u64;
 	type ConcreteId = Nft<u64>;
 	type AfterCreate = ();
+	type CallMagnitude = ();
+	type PreimageCurrency = Balances;
+	type PreimageBaseDeposit = ConstU64<1>;
+	type PreimageByteDeposit = ConstU64<1>;
+	type MaxPreimageLen = ConstU32<1024>;
 	type WeightInfo = ();
 }
 
@@ -107,6 +112,7 @@ impl sudo::Config for Test {
 parameter_types! {
 	pub const MinPledge: u64 = 100u64;
 	pub const TrackPeriod: u64 = 100u64;
+	pub const MaxEnactmentsPerBlock: u32 = 10;
 }
 
 impl emergency::Config for Test {
@@ -115,6 +121,7 @@ impl emergency::Config for Test {
 	type Currency = Balances;
 	type MinPledge = MinPledge;
 	type TrackPeriod = TrackPeriod;
+	type MaxEnactmentsPerBlock = MaxEnactmentsPerBlock;
 	type WeightInfo = ();
 }
 
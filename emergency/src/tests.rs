@@ -2,9 +2,12 @@
 
 use super::*;
 use crate::mock::{Call, Origin, *};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use frame_support::traits::Hooks;
 use primitives::ids::Nft;
-use sp_runtime::traits::BlakeTwo256;
+use daos_agency::traits::ChangeMembers;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
@@ -42,13 +45,21 @@ fn get_proposal() -> Vec<u8> {
 	Call::Emergency(crate::Call::set_pledge { dao_id: 0u64, amount: 1000u64 }).encode()
 }
 
+fn note_proposal() -> PreimageRef<H256> {
+	let bytes = get_proposal();
+	let preimage = PreimageRef { hash: BlakeTwo256::hash(&bytes[..]), len: bytes.len() as u32 };
+	assert_ok!(dao::Pallet::<Test>::note_preimage(Origin::signed(BOB), 0u64, bytes));
+	preimage
+}
+
 fn external() {
+	create_dao();
 	rec_balance();
-	let proposal = Call::decode(&mut &get_proposal()[..]).unwrap();
+	let proposal = note_proposal();
 	assert_ok!(crate::Pallet::<Test>::external_track(
 		Origin::root(),
 		0u64,
-		Box::new(proposal),
+		proposal,
 		vec![1, 2, 3, 4]
 	));
 	assert!(crate::HashesOf::<Test>::get(0u64).len() > 0);
@@ -57,17 +68,17 @@ fn external() {
 fn internal() {
 	set_members();
 	rec_balance();
-	let proposal = Call::decode(&mut &get_proposal()[..]).unwrap();
+	let proposal = note_proposal();
 	assert_ok!(crate::Pallet::<Test>::internal_track(
 		Origin::signed(ALICE),
 		0u64,
-		Box::new(proposal.clone()),
+		proposal,
 		vec![1, 2, 3, 4],
 	));
 	assert!(crate::Pallet::<Test>::internal_track(
 		Origin::signed(ALICE),
 		0u64,
-		Box::new(proposal),
+		proposal,
 		vec![1, 2, 3, 4]
 	)
 	.is_err());
@@ -177,3 +188,82 @@ fn enact_proposal_should_work() {
 		assert_eq!(crate::PledgeOf::<Test>::get(0u64), 1000);
 	});
 }
+
+#[test]
+fn on_initialize_should_enact() {
+	new_test_ext().execute_with(|| {
+		internal();
+		let hash = BlakeTwo256::hash(&get_proposal()[..]);
+		let end = crate::ProposalOf::<Test>::get(0u64, hash).unwrap().end_block;
+		assert_eq!(crate::Pallet::<Test>::enactments_due(end), vec![(0u64, hash)]);
+		frame_system::Pallet::<Test>::set_block_number(end.into());
+		crate::Pallet::<Test>::on_initialize(end.into());
+		assert_eq!(crate::PledgeOf::<Test>::get(0u64), 1000);
+		assert!(!crate::HashesOf::<Test>::get(0u64).contains(&hash));
+		assert!(crate::Pallet::<Test>::enact_proposal(Origin::signed(BOB), 0u64, hash).is_err());
+
+		// Proposals over the per block limit wait until there is weight left to enact them.
+		let due = (0..12u64).map(|i| (0u64, H256::repeat_byte(i as u8))).collect::<Vec<_>>();
+		crate::EnactmentsDue::<Test>::insert(2000u32, due.clone());
+		crate::Pallet::<Test>::on_initialize(2000);
+		assert_eq!(crate::Pallet::<Test>::enactments_overdue(), due[10..].to_vec());
+		crate::Pallet::<Test>::on_idle(2000, Weight::zero());
+		assert_eq!(crate::Pallet::<Test>::enactments_overdue(), due[10..].to_vec());
+		crate::Pallet::<Test>::on_idle(2000, Weight::MAX);
+		assert!(crate::Pallet::<Test>::enactments_overdue().is_empty());
+	});
+}
+
+#[test]
+fn on_idle_should_count_the_enacted_call() {
+	new_test_ext().execute_with(|| {
+		internal();
+		let hash = BlakeTwo256::hash(&get_proposal()[..]);
+		let end = crate::ProposalOf::<Test>::get(0u64, hash).unwrap().end_block;
+		frame_system::Pallet::<Test>::set_block_number(end.into());
+		crate::EnactmentsOverdue::<Test>::put(vec![(0u64, hash)]);
+		let enact = <() as crate::weights::WeightInfo>::enact_proposal();
+		let call = Call::Emergency(crate::Call::set_pledge { dao_id: 0u64, amount: 1000u64 })
+			.get_dispatch_info()
+			.weight;
+
+		// The weight of the proposal alone is not enough for its call.
+		assert_eq!(crate::Pallet::<Test>::on_idle(end.into(), enact), Weight::zero());
+		assert_eq!(crate::Pallet::<Test>::enactments_overdue(), vec![(0u64, hash)]);
+		assert_eq!(
+			crate::Pallet::<Test>::on_idle(end.into(), enact.saturating_add(call)),
+			enact.saturating_add(call)
+		);
+		assert!(crate::Pallet::<Test>::enactments_overdue().is_empty());
+		assert_eq!(crate::PledgeOf::<Test>::get(0u64), 1000);
+	});
+}
+
+#[test]
+fn undecodable_proposal_should_fail() {
+	new_test_ext().execute_with(|| {
+		internal();
+		let hash = BlakeTwo256::hash(&get_proposal()[..]);
+		let end = crate::ProposalOf::<Test>::get(0u64, hash).unwrap().end_block;
+		dao::PreimageFor::<Test>::insert(0u64, hash, BoundedVec::truncate_from(vec![1]));
+		frame_system::Pallet::<Test>::set_block_number(end.into());
+		crate::Pallet::<Test>::on_initialize(end.into());
+		assert!(!crate::HashesOf::<Test>::get(0u64).contains(&hash));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_ne!(crate::PledgeOf::<Test>::get(0u64), 1000);
+	});
+}
+
+#[test]
+fn change_members_should_sync() {
+	new_test_ext().execute_with(|| {
+		set_members();
+		<crate::Pallet<Test> as ChangeMembers<u64, u64>>::change_members_sorted(
+			0u64,
+			&[3u64],
+			&[ALICE],
+			&[BOB, 3u64],
+		);
+		assert_eq!(crate::Members::<Test>::get(0u64), vec![BOB, 3u64]);
+	});
+}
@@ -2,3 +2,30 @@
 // 	use frame_support::weights::Weight;
 // 	pub const DAOS_BASE_WEIGHT: Weight = 20_0000_0000.into();
 // }
+
+/// The maximum length of an IPFS CID that describes a proposal.
+pub const MAX_DESCRIPTION_HASH_LENGTH: u32 = 64;
+
+/// The maximum depth of a composed `DoAsEnsureOrigin`.
+pub const MAX_ENSURE_ORIGIN_DEPTH: u32 = 4;
+
+/// The maximum number of committees in `DoAsEnsureOrigin::AnyOf`.
+pub const MAX_ENSURE_ORIGIN_COMMITTEES: u32 = 8;
+
+/// The maximum number of magnitude tiers of a call.
+pub const MAX_MAGNITUDE_TIERS: u32 = 8;
+
+/// The maximum length of the name of a permission preset.
+pub const MAX_PRESET_NAME_LENGTH: u32 = 32;
+
+/// The maximum number of calls a batch setter or a permission preset covers.
+pub const MAX_PRESET_CALLS: u32 = 64;
+
+/// The maximum number of signed votes submitted in a batch.
+pub const MAX_SIGNED_VOTES: u32 = 64;
+
+/// The context of the payload of a vote signed off-chain.
+pub const SIGNED_VOTE_CONTEXT: &[u8] = b"daos/agency/signed-vote";
+
+/// The maximum depth of a call decoded from a preimage.
+pub const MAX_PREIMAGE_DECODE_DEPTH: u32 = 256;
@@ -1,7 +1,14 @@
 use super::*;
 pub use codec::MaxEncodedLen;
+use frame_support::{
+	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use sp_runtime::traits::{CheckedAdd, One};
-use sp_std::ops::{Add, Mul};
+use sp_std::{
+	fmt::Debug,
+	marker::PhantomData,
+	ops::{Add, Mul},
+};
 
 #[derive(Decode, Encode, Copy, Clone, Default, Debug, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
 pub struct DaoId(pub u64);
@@ -48,8 +55,32 @@ impl CheckedAdd for DaoId {
 #[derive(Decode, Encode, Copy, Clone, Default, Debug, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
 pub struct Nft<ClassId>(pub ClassId);
 
-#[derive(Decode, Encode, Copy, Clone, Default, Debug, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
-pub struct Fungible<TokenId>(pub TokenId);
+/// A DAO of the holders of a fungible asset, which is looked up in `Assets` when the DAO is
+/// created.
+#[derive(
+	Decode,
+	Encode,
+	CloneNoBound,
+	DefaultNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+	EqNoBound,
+	PartialEqNoBound,
+)]
+#[scale_info(skip_type_params(Assets))]
+pub struct Fungible<TokenId: Copy + Default + Debug + Eq, Assets>(
+	pub TokenId,
+	pub PhantomData<Assets>,
+);
+
+impl<TokenId: Copy + Default + Debug + Eq, Assets> Copy for Fungible<TokenId, Assets> {}
+
+impl<TokenId: Copy + Default + Debug + Eq, Assets> Fungible<TokenId, Assets> {
+	pub fn new(token_id: TokenId) -> Self {
+		Self(token_id, PhantomData)
+	}
+}
 
 #[derive(Decode, Encode, Copy, Clone, Default, Debug, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
 pub struct RoomId<Id>(pub Id);
@@ -75,7 +106,9 @@ impl<T: Encode + Decode, ClassId: Encode + Decode> AccountIdConversion<T> for Nf
 	}
 }
 
-impl<T: Encode + Decode, TokenId: Encode + Decode> AccountIdConversion<T> for Fungible<TokenId> {
+impl<T: Encode + Decode, TokenId: Encode + Decode + Copy + Default + Debug + Eq, Assets>
+	AccountIdConversion<T> for Fungible<TokenId, Assets>
+{
 	fn into_account(&self) -> T {
 		(b"fung", self).using_encoded(|b| T::decode(&mut TrailingZeroInput(b))).unwrap()
 	}
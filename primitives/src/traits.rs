@@ -1,5 +1,7 @@
 use super::*;
-use sp_runtime::DispatchError;
+use frame_support::traits::tokens::fungibles;
+use sp_runtime::{DispatchError, TokenError};
+use sp_std::fmt::Debug;
 
 pub struct BadOrigin;
 
@@ -21,6 +23,21 @@ pub trait SetCollectiveMembers<AccountId: Clone + Ord, DaoId: Clone + Default +
 	) -> result::Result<(), DispathErr>;
 }
 
+/// Get a numeric magnitude out of a call, such as the amount of a transfer.
+///
+/// Permissions can depend on the magnitude, so that a large spend needs more approval than a
+/// small one.
+pub trait CallMagnitude<Call> {
+	/// The magnitude of the call, or `None` if it has none.
+	fn magnitude(call: &Call) -> Option<u128>;
+}
+
+impl<Call> CallMagnitude<Call> for () {
+	fn magnitude(_call: &Call) -> Option<u128> {
+		None
+	}
+}
+
 pub trait AfterCreate<A, B> {
 	fn do_something(a: A, b: B);
 }
@@ -85,6 +102,24 @@ impl<AccountId: Clone + Ord, DaoId: Default + Clone, Id: Clone>
 	}
 }
 
+/// A fungible DAO can only be created for an asset that exists in `Assets`.
+impl<AccountId, DaoId, Id, Assets> TryCreate<AccountId, DaoId, DispatchError>
+	for ids::Fungible<Id, Assets>
+where
+	AccountId: Clone + Ord,
+	DaoId: Default + Clone,
+	Id: Copy + Default + Debug + Eq,
+	Assets: fungibles::Inspect<AccountId, AssetId = Id>,
+{
+	fn try_create(&self, _who: AccountId, _dao_id: DaoId) -> Result<(), DispatchError> {
+		if Assets::asset_exists(self.0) {
+			Ok(())
+		} else {
+			Err(TokenError::UnknownAsset.into())
+		}
+	}
+}
+
 // impl<Call: Clone, Id: Default> BaseCallFilter<Call> for ids::Nft<Id> {
 // 	fn contains(&self, _call: Call) -> bool {
 // 		true
@@ -1,4 +1,8 @@
 use super::*;
+use crate::constant::{
+	MAX_DESCRIPTION_HASH_LENGTH, MAX_ENSURE_ORIGIN_COMMITTEES, MAX_PRESET_NAME_LENGTH,
+};
+use frame_support::{traits::ConstU32, BoundedVec};
 
 /// Simple index type for proposal counting.
 pub type ProposalIndex = u32;
@@ -11,6 +15,12 @@ pub type MemberCount = u32;
 
 pub type RealCallId = u32;
 
+/// The id of a committee within a DAO.
+pub type CommitteeId = u32;
+
+/// The name of a permission preset, such as `conservative` or `fast`.
+pub type PresetName = BoundedVec<u8, ConstU32<MAX_PRESET_NAME_LENGTH>>;
+
 #[derive(PartialEq, Encode, Decode, RuntimeDebug, Clone, TypeInfo, MaxEncodedLen)]
 pub enum Proportion<MemberCount> {
 	MoreThan(MemberCount, MemberCount),
@@ -23,17 +33,53 @@ impl Default for Proportion<MemberCount> {
 	}
 }
 
+/// The origin a call needs, which can be composed of other ones.
 #[cfg_attr(any(feature = "std", test), derive(Debug))]
-#[derive(PartialEq, Encode, Decode, Clone, TypeInfo, Copy, MaxEncodedLen)]
-pub enum DoAsEnsureOrigin<Pro, C> {
+#[derive(PartialEq, Encode, Decode, Clone, TypeInfo, Default)]
+pub enum DoAsEnsureOrigin<Pro, C, AccountId> {
 	Proportion(Pro),
 	Member,
 	Members(C),
+	#[default]
 	Root,
+	/// A specific account, such as the DAO account that square referendums dispatch with.
+	Account(AccountId),
+	/// A motion passed by any of these committees.
+	AnyOf(BoundedVec<CommitteeId, ConstU32<MAX_ENSURE_ORIGIN_COMMITTEES>>),
+	/// Both origins are needed.
+	And(Box<Self>, Box<Self>),
+	/// Either origin is enough.
+	Or(Box<Self>, Box<Self>),
 }
 
-impl<Pro: Default, C: Default> Default for DoAsEnsureOrigin<Pro, C> {
-	fn default() -> Self {
-		Self::Root
+impl<Pro, C, AccountId> DoAsEnsureOrigin<Pro, C, AccountId> {
+	/// The depth of the composed origin, which is 1 for an origin that is not composed.
+	pub fn depth(&self) -> u32 {
+		match self {
+			Self::And(a, b) | Self::Or(a, b) => a.depth().max(b.depth()).saturating_add(1),
+			_ => 1,
+		}
 	}
 }
+
+/// The IPFS CID of a proposal's full description.
+pub type DescriptionHash = BoundedVec<u8, ConstU32<MAX_DESCRIPTION_HASH_LENGTH>>;
+
+/// Information that tells voters why a proposal exists.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, Clone, TypeInfo, MaxEncodedLen, Default)]
+pub struct ProposalMetadata<Title, Cid> {
+	/// A short title of the proposal.
+	pub title: Option<Title>,
+	/// The IPFS CID of the full description.
+	pub description_hash: Option<Cid>,
+}
+
+/// A call noted in the preimage store of a DAO, referred to by the hash and the length of its
+/// encoding.
+#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub struct PreimageRef<Hash> {
+	/// The hash of the encoded call.
+	pub hash: Hash,
+	/// The length of the encoded call.
+	pub len: u32,
+}
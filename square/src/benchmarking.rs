@@ -9,8 +9,9 @@ use dao::Call as DaoCall;
 use frame_benchmarking::{
 	account, benchmarks, benchmarks_instance, impl_benchmark_test_suite, whitelisted_caller,
 };
+use frame_support::sp_runtime::traits::{Bounded, Hash};
 use frame_system::RawOrigin as SystemOrigin;
-use primitives::{types::ProposalIndex, AccountIdConversion};
+use primitives::{constant::MAX_PRESET_NAME_LENGTH, types::ProposalIndex, AccountIdConversion};
 use sp_runtime::SaturatedConversion;
 use sp_std::vec;
 
@@ -42,10 +43,21 @@ fn creat_dao<T: Config>() -> (T::DaoId, T::ConcreteId) {
 	(dao_id, second_id)
 }
 
-fn get_call<T: Config>(dao_id: T::DaoId) -> (<T as dao::Config>::Call, T::Hash) {
+fn get_remark<T: Config>(dao_id: T::DaoId) -> Vec<u8> {
 	let proposal: <T as dao::Config>::Call =
 		DaoCall::<T>::dao_remark { dao_id, remark: vec![1; 20] }.into();
-	(proposal.clone(), T::Hashing::hash_of(&proposal))
+	proposal.encode()
+}
+
+fn get_call<T: Config>(dao_id: T::DaoId) -> (PreimageRef<T::Hash>, T::Hash) {
+	let bytes = get_remark::<T>(dao_id);
+	let preimage = PreimageRef { hash: T::Hashing::hash(&bytes[..]), len: bytes.len() as u32 };
+	let alice = get_alice::<T>();
+	let amount = dao::PreimageBalanceOf::<T>::max_value() / 2u32.into();
+	T::PreimageCurrency::make_free_balance_be(&alice, amount);
+	let origin = SystemOrigin::Signed(alice).into();
+	assert!(dao::Pallet::<T>::note_preimage(origin, dao_id, bytes).is_ok());
+	(preimage, preimage.hash)
 }
 
 fn create_proposal<T: Config>() -> (T::DaoId, T::ConcreteId, ProposalIndex) {
@@ -55,8 +67,10 @@ fn create_proposal<T: Config>() -> (T::DaoId, T::ConcreteId, ProposalIndex) {
 	assert!(Democracy::<T>::propose(
 		SystemOrigin::Signed(get_alice::<T>()).into(),
 		dao_id,
-		Box::new(proposal),
-		amount
+		proposal,
+		amount,
+		None,
+		None
 	)
 	.is_ok());
 	frame_system::Pallet::<T>::set_block_number(T::BlockNumber::from(LaunchTime));
@@ -102,13 +116,19 @@ benchmarks! {
 		let (dao_id, second_id) = creat_dao::<T>();
 		let (proposal, _) = get_call::<T>(dao_id);
 		let amount = (1000 * DOLLARS).saturated_into::<BalanceOf<T>>();
-	}:_(SystemOrigin::Signed(get_alice::<T>()), dao_id, Box::new(proposal), amount)
+	}:_(SystemOrigin::Signed(get_alice::<T>()), dao_id, proposal, amount, Some(vec![1; 20]), Some(vec![1; 46]))
 
 	second {
 		let (dao_id, second_id, index) = create_proposal::<T>();
 		let dao = get_dao_account::<T>(second_id);
 	}:_(SystemOrigin::Signed(dao), dao_id, index)
 
+	withdraw {
+		let (dao_id, second_id, index) = create_proposal::<T>();
+		let dao = get_dao_account::<T>(second_id);
+		assert!(Democracy::<T>::second(SystemOrigin::Signed(dao).into(), dao_id, index).is_ok());
+	}:_(SystemOrigin::Signed(get_alice::<T>()), dao_id, index)
+
 	open_table {
 		let (dao_id, second_id, index) = create_proposal::<T>();
 		let dao = get_dao_account::<T>(second_id);
@@ -132,6 +152,23 @@ benchmarks! {
 		let account = enact::<T>();
 	}:_(SystemOrigin::Signed(account))
 
+	delegate {
+		let (dao_id, dao_account, index) = vote1::<T>();
+	}:_(SystemOrigin::Signed(get_alice::<T>()), dao_id, dao_account, T::Pledge::default(), T::Conviction::default())
+
+	undelegate {
+		let (dao_id, dao_account, index) = vote1::<T>();
+		let alice = get_alice::<T>();
+		assert!(Democracy::<T>::delegate(
+			SystemOrigin::Signed(alice.clone()).into(),
+			dao_id,
+			dao_account,
+			T::Pledge::default(),
+			T::Conviction::default()
+		)
+		.is_ok());
+	}:_(SystemOrigin::Signed(alice), dao_id)
+
 	set_min_vote_weight_for_every_call {
 		let (dao_id, second_id) = creat_dao::<T>();
 		let dao = get_dao_account::<T>(second_id);
@@ -166,4 +203,65 @@ benchmarks! {
 		let (dao_id, second_id) = creat_dao::<T>();
 		let dao = get_dao_account::<T>(second_id);
 	}:_(SystemOrigin::Signed(dao), dao_id, T::BlockNumber::from(100u32))
+
+	set_min_vote_weight_tiers {
+		let (dao_id, second_id) = creat_dao::<T>();
+		let dao = get_dao_account::<T>(second_id);
+		let tiers = (0..MAX_MAGNITUDE_TIERS)
+			.map(|i| (i as u128 * 1000, (i as u128 * DOLLARS).saturated_into::<BalanceOf<T>>()))
+			.collect::<Vec<_>>();
+	}:_(SystemOrigin::Signed(dao), dao_id, T::CallId::default(), tiers)
+
+	set_min_vote_weights {
+		let (dao_id, second_id) = creat_dao::<T>();
+		let dao = get_dao_account::<T>(second_id);
+		let weights = vec![(T::CallId::default(), (100 * DOLLARS).saturated_into::<BalanceOf<T>>()); MAX_PRESET_CALLS as usize];
+	}:_(SystemOrigin::Signed(dao), dao_id, weights)
+
+	set_voting_curve {
+		let (dao_id, second_id) = creat_dao::<T>();
+		let dao = get_dao_account::<T>(second_id);
+	}:_(SystemOrigin::Signed(dao), dao_id, VotingCurve::Quadratic)
+
+	set_approval_rule {
+		let (dao_id, second_id) = creat_dao::<T>();
+		let dao = get_dao_account::<T>(second_id);
+		let rule = ApprovalRule {
+			turnout: Perbill::from_percent(20),
+			approval: Perbill::from_percent(66),
+		};
+	}:_(SystemOrigin::Signed(dao), dao_id, T::CallId::default(), Some(rule))
+
+	register_min_vote_weight_preset {
+		let weights = vec![(T::CallId::default(), (100 * DOLLARS).saturated_into::<BalanceOf<T>>()); MAX_PRESET_CALLS as usize];
+	}:_(SystemOrigin::Root, vec![1; MAX_PRESET_NAME_LENGTH as usize], weights)
+
+	apply_min_vote_weight_preset {
+		let (dao_id, second_id) = creat_dao::<T>();
+		let dao = get_dao_account::<T>(second_id);
+		let weights = vec![(T::CallId::default(), (100 * DOLLARS).saturated_into::<BalanceOf<T>>()); MAX_PRESET_CALLS as usize];
+		assert!(Democracy::<T>::register_min_vote_weight_preset(
+			SystemOrigin::Root.into(),
+			vec![1; MAX_PRESET_NAME_LENGTH as usize],
+			weights
+		)
+		.is_ok());
+	}:_(SystemOrigin::Signed(dao), dao_id, vec![1; MAX_PRESET_NAME_LENGTH as usize])
+
+	cancel_referendum {
+		let (dao_id, second_id, index) = launch::<T>();
+		let dao = get_dao_account::<T>(second_id);
+	}:_(SystemOrigin::Signed(dao), dao_id, index)
+
+	blacklist_proposal {
+		let (dao_id, second_id, index) = launch::<T>();
+		let dao = get_dao_account::<T>(second_id);
+		// the proposal was noted when it was launched.
+		let proposal_hash = T::Hashing::hash(&get_remark::<T>(dao_id)[..]);
+	}:_(SystemOrigin::Signed(dao), dao_id, proposal_hash, Some(index))
+
+	set_blacklist_period {
+		let (dao_id, second_id) = creat_dao::<T>();
+		let dao = get_dao_account::<T>(second_id);
+	}:_(SystemOrigin::Signed(dao), dao_id, 100u32)
 }
@@ -22,6 +22,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::type_complexity)]
+#![recursion_limit = "256"]
 
 //! # Square Module
 //!
@@ -33,23 +34,28 @@
 extern crate core;
 
 pub use codec::{Decode, Encode};
-use sp_runtime::traits::Hash;
 use dao::{self, Vec};
  // use daos_sudo::UnfilteredDispatchable;
  use frame_support::traits::UnfilteredDispatchable;
-use frame_support::dispatch::{DispatchResult as DResult,};
+use frame_support::dispatch::{DispatchResult as DResult, GetDispatchInfo};
+use frame_support::weights::Weight;
+use frame_support::ensure;
 pub use frame_support::{
 	traits::{Currency, Defensive, Get, ReservableCurrency},
 	BoundedVec, 
 };
 pub use pallet::*;
+use primitives::{
+	constant::{MAX_MAGNITUDE_TIERS, MAX_PRESET_CALLS},
+	traits::{BaseCallFilter, CallMagnitude},
+	types::{DescriptionHash, PreimageRef, PresetName, ProposalMetadata},
+};
 use scale_info::TypeInfo;
 pub use sp_runtime::traits::{Saturating, Zero};
 use sp_runtime::{
-	traits::{BlockNumberProvider, CheckedAdd, CheckedMul},
-	DispatchError,
+	traits::{AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, CheckedMul, IntegerSquareRoot},
+	DispatchError, Perbill, SaturatedConversion,
 };
-use sp_std::boxed::Box;
 pub use sp_std::{fmt::Debug, result};
 pub use traits::*;
 use weights::WeightInfo;
@@ -67,6 +73,8 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod pledge;
+pub mod runtime_api;
 pub mod traits;
 pub mod weights;
 
@@ -77,6 +85,31 @@ pub struct Tally<Balance> {
 	pub ayes: Balance,
 	/// The number of nay votes, expressed in terms of post-conviction lock-vote.
 	pub nays: Balance,
+	/// The number of abstentions, which count toward turnout but not toward approval.
+	pub abstentions: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> Tally<Balance> {
+	/// Add a vote of `weight` with `opinion`.
+	pub fn add(&mut self, opinion: &Opinion, weight: Balance) {
+		let (ayes, nays, abstentions) = opinion.split(weight);
+		self.ayes = self.ayes.saturating_add(ayes);
+		self.nays = self.nays.saturating_add(nays);
+		self.abstentions = self.abstentions.saturating_add(abstentions);
+	}
+
+	/// Remove a vote of `weight` with `opinion`.
+	pub fn remove(&mut self, opinion: &Opinion, weight: Balance) {
+		let (ayes, nays, abstentions) = opinion.split(weight);
+		self.ayes = self.ayes.saturating_sub(ayes);
+		self.nays = self.nays.saturating_sub(nays);
+		self.abstentions = self.abstentions.saturating_sub(abstentions);
+	}
+
+	/// All the votes cast, abstentions included.
+	pub fn turnout(&self) -> Balance {
+		self.ayes.saturating_add(self.nays).saturating_add(self.abstentions)
+	}
 }
 
 /// vote yes or no
@@ -86,6 +119,80 @@ pub enum Opinion {
 	AYES,
 	/// Reject.
 	NAYS,
+	/// Split the weight between ayes and nays in the ratio of `aye` to `nay`, such as for a
+	/// custodian voting for many users.
+	Split { aye: u128, nay: u128 },
+	/// Count toward turnout but not toward approval.
+	Abstain,
+}
+
+impl Opinion {
+	/// The ayes, nays and abstentions a vote of `weight` adds to the tally.
+	pub fn split<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		weight: Balance,
+	) -> (Balance, Balance, Balance) {
+		let zero = Zero::zero();
+		match self {
+			Opinion::AYES => (weight, zero, zero),
+			Opinion::NAYS => (zero, weight, zero),
+			Opinion::Split { aye, nay } => {
+				let ayes = Perbill::from_rational(*aye, aye.saturating_add(*nay)).mul_floor(weight);
+				(ayes, weight.saturating_sub(ayes), zero)
+			},
+			Opinion::Abstain => (zero, zero, weight),
+		}
+	}
+}
+
+/// How the pledged weight of a vote counts in the tally.
+#[derive(Encode, Decode, Default, Copy, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub enum VotingCurve {
+	/// The weight counts as it is.
+	#[default]
+	Linear,
+	/// The integer square root of the weight counts, and each identity votes once in a
+	/// referendum.
+	Quadratic,
+}
+
+impl VotingCurve {
+	/// The weight a vote adds to the tally.
+	pub fn tally_weight<Balance: IntegerSquareRoot>(self, weight: Balance) -> Balance {
+		match self {
+			VotingCurve::Linear => weight,
+			VotingCurve::Quadratic => weight.integer_sqrt(),
+		}
+	}
+}
+
+/// The turnout and the share of ayes a referendum on a call needs to pass.
+#[derive(Encode, Decode, Default, Copy, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct ApprovalRule {
+	/// The votes cast, measured against the total voting supply of the pledge.
+	pub turnout: Perbill,
+	/// The ayes, measured against the votes cast.
+	pub approval: Perbill,
+}
+
+impl ApprovalRule {
+	/// Whether the votes cast reach the turnout of `supply`.
+	pub fn turnout_reached<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		turnout: Balance,
+		supply: Balance,
+	) -> bool {
+		turnout >= self.turnout.mul_ceil(supply)
+	}
+
+	/// Whether the ayes reach the approval of the votes cast.
+	pub fn is_approved<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		ayes: Balance,
+		nays: Balance,
+	) -> bool {
+		ayes >= self.approval.mul_ceil(ayes.saturating_add(nays))
+	}
 }
 
 /// Information about individual votes.
@@ -101,19 +208,41 @@ pub struct VoteInfo<DaoId, ConcreteId, Pledge, BlockNumber, VoteWeight, Opinion,
 	opinion: Opinion,
 	/// voting weight.
 	vote_weight: VoteWeight,
+	/// The weight delegated to the voter counted along with the vote, on the first vote of the
+	/// voter in the referendum.
+	delegated_weight: VoteWeight,
 	/// Block height that can be unlocked.
 	unlock_block: BlockNumber,
 	/// The referendum id corresponding to the vote.
 	referendum_index: ReferendumIndex,
 }
 
+/// The weight of an account voting through another account in the referendums of a DAO.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct Delegation<AccountId, ConcreteId, Pledge, Conviction, VoteWeight> {
+	/// The account whose votes the weight flows to.
+	target: AccountId,
+	/// The specific group id mapped by Dao.
+	concrete_id: ConcreteId,
+	/// The specific thing that the delegation pledged.
+	pledge: Pledge,
+	/// The number of times the weight is magnified.
+	conviction: Conviction,
+	/// The weight of the pledge with the conviction.
+	vote_weight: VoteWeight,
+	/// How long the pledge stays locked once the delegation is revoked.
+	lock_duration: u32,
+	/// Block height that can be unlocked, once the delegation is revoked.
+	unlock_block: Option<u32>,
+}
+
 /// Info regarding an ongoing referendum.
 #[derive(Encode, Decode, Clone, PartialEq, Eq,  TypeInfo)]
-pub struct ReferendumStatus<BlockNumber, Call, Balance> {
+pub struct ReferendumStatus<BlockNumber, Proposal, Balance> {
 	/// When voting on this referendum will end.
 	pub end: BlockNumber,
-	/// The hash of the proposal being voted on.
-	pub proposal: Call,
+	/// The preimage of the proposal being voted on.
+	pub proposal: Proposal,
 	/// The delay (in blocks) to wait after a successful referendum before deploying.
 	pub delay: BlockNumber,
 	/// The current tally of votes in this referendum.
@@ -122,9 +251,9 @@ pub struct ReferendumStatus<BlockNumber, Call, Balance> {
 
 /// Info regarding a referendum, present or past.
 #[derive(Encode, Decode, Clone, PartialEq, Eq,  TypeInfo)]
-pub enum ReferendumInfo<BlockNumber, Call, Balance> {
+pub enum ReferendumInfo<BlockNumber, Proposal, Balance> {
 	/// Referendum is happening, the arg is the block number at which it will end.
-	Ongoing(ReferendumStatus<BlockNumber, Call, Balance>),
+	Ongoing(ReferendumStatus<BlockNumber, Proposal, Balance>),
 	/// Referendum finished at `end`, and has been `approved` or rejected.
 	Finished { approved: bool, end: BlockNumber },
 }
@@ -132,13 +261,50 @@ pub enum ReferendumInfo<BlockNumber, Call, Balance> {
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use dao::BaseCallFilter;
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	/// The minimum voting weights from a given magnitude on, in ascending order of magnitude.
+	pub type MinVoteWeightTiersOf<T> =
+		BoundedVec<(u128, BalanceOf<T>), ConstU32<MAX_MAGNITUDE_TIERS>>;
+
+	/// The minimum voting weights of some calls, set at once.
+	pub type MinVoteWeightsOf<T> = BoundedVec<
+		(<T as dao::Config>::CallId, BalanceOf<T>),
+		ConstU32<MAX_PRESET_CALLS>,
+	>;
+
+	/// A vote of an account.
+	pub type VoteInfoOf<T> = VoteInfo<
+		<T as dao::Config>::DaoId,
+		<T as dao::Config>::ConcreteId,
+		<T as Config>::Pledge,
+		u32,
+		BalanceOf<T>,
+		Opinion,
+		ReferendumIndex,
+	>;
+
+	/// A delegation of an account.
+	pub type DelegationOf<T> = Delegation<
+		<T as frame_system::Config>::AccountId,
+		<T as dao::Config>::ConcreteId,
+		<T as Config>::Pledge,
+		<T as Config>::Conviction,
+		BalanceOf<T>,
+	>;
+
+	pub type VoterIdentityOf<T> = <<T as Config>::VoterIdentity as VoterIdentity<
+		<T as frame_system::Config>::AccountId,
+	>>::Identity;
+
+	/// The title and description of a proposal.
+	pub type BoundedMetadataOf<T> =
+		ProposalMetadata<BoundedVec<u8, <T as Config>::MaxTitleLength>, DescriptionHash>;
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config + dao::Config {
@@ -165,8 +331,16 @@ pub mod pallet {
 			+ Parameter
 			+ ConvertInto<u32>
 			+ ConvertInto<BalanceOf<Self>>;
+		/// The identity an account votes as, when the voting curve of a DAO is quadratic.
+		type VoterIdentity: VoterIdentity<Self::AccountId>;
 		/// Operations related to native assets.
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+		/// The maximum length of a proposal's title.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+		/// The maximum number of launches, and of enactments, done automatically in a block.
+		#[pallet::constant]
+		type MaxScheduledPerBlock: Get<u32>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -242,17 +416,40 @@ pub mod pallet {
 	pub type EnactmentPeriod<T: Config> =
 		StorageMap<_, Identity, T::DaoId, u32, ValueQuery, EnactmentPeriodOnEmpty<T>>;
 
-	/// The public proposals. Unsorted. The second item is the proposal's hash.
+	#[pallet::type_value]
+	pub fn BlacklistPeriodOnEmpty<T: Config>() -> u32 {
+		900u32
+	}
+
+	/// How long a blacklisted proposal can not be proposed again.
+	#[pallet::storage]
+	#[pallet::getter(fn blacklist_period)]
+	pub type BlacklistPeriod<T: Config> =
+		StorageMap<_, Identity, T::DaoId, u32, ValueQuery, BlacklistPeriodOnEmpty<T>>;
+
+	/// Proposal hashes that can not be proposed until the given block.
+	#[pallet::storage]
+	#[pallet::getter(fn blacklist)]
+	pub type Blacklist<T: Config> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, T::Hash, u32, OptionQuery>;
+
+	/// The public proposals. Unsorted. The second item is the proposal's preimage.
 	#[pallet::storage]
 	#[pallet::getter(fn public_props)]
 	pub type PublicProps<T: Config> = StorageMap<
 		_,
 		Identity,
 		T::DaoId,
-		Vec<(PropIndex, T::Hash, <T as dao::Config>::Call, T::AccountId)>,
+		Vec<(PropIndex, PreimageRef<T::Hash>, T::AccountId)>,
 		ValueQuery,
 	>;
 
+	/// The title and description of each public proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_metadata_of)]
+	pub type ProposalMetadataOf<T: Config> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, PropIndex, BoundedMetadataOf<T>>;
+
 	/// Those who have locked a deposit.
 	///
 	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
@@ -267,11 +464,16 @@ pub mod pallet {
 		(Vec<T::AccountId>, BalanceOf<T>),
 	>;
 
-	/// Amount of proposal locked.
+	/// Amount of proposal locked, with the block it unlocks at and the proposal it seconds.
 	#[pallet::storage]
 	#[pallet::getter(fn reserve_of)]
-	pub type ReserveOf<T: Config> =
-		StorageMap<_, Identity, T::AccountId, Vec<(BalanceOf<T>, u32)>, ValueQuery>;
+	pub type ReserveOf<T: Config> = StorageMap<
+		_,
+		Identity,
+		T::AccountId,
+		Vec<(BalanceOf<T>, u32, (T::DaoId, PropIndex))>,
+		ValueQuery,
+	>;
 
 	/// Referendum specific information.
 	#[pallet::storage]
@@ -282,9 +484,28 @@ pub mod pallet {
 		T::DaoId,
 		Identity,
 		ReferendumIndex,
-		ReferendumInfo<u32, <T as dao::Config>::Call, BalanceOf<T>>,
+		ReferendumInfo<u32, PreimageRef<T::Hash>, BalanceOf<T>>,
+	>;
+
+	/// The deposits of each ongoing referendum, taken over from its public proposal along with
+	/// the index of that proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_deposit_of)]
+	pub type ReferendumDepositOf<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		ReferendumIndex,
+		(PropIndex, Vec<T::AccountId>, BalanceOf<T>),
 	>;
 
+	/// The title and description of each ongoing referendum, taken over from its public proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_metadata_of)]
+	pub type ReferendumMetadataOf<T: Config> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, ReferendumIndex, BoundedMetadataOf<T>>;
+
 	/// Number of referendums so far.
 	#[pallet::storage]
 	#[pallet::getter(fn referendum_count)]
@@ -294,21 +515,31 @@ pub mod pallet {
 	/// Everyone's voting information.
 	#[pallet::storage]
 	#[pallet::getter(fn votes_of)]
-	pub type VotesOf<T: Config> = StorageMap<
+	pub type VotesOf<T: Config> =
+		StorageMap<_, Identity, T::AccountId, Vec<VoteInfoOf<T>>, ValueQuery>;
+
+	/// The delegation of each account in each DAO, kept after it is revoked until it is unlocked.
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config> = StorageDoubleMap<
 		_,
+		Blake2_128Concat,
+		T::AccountId,
 		Identity,
+		T::DaoId,
+		DelegationOf<T>,
+	>;
+
+	/// The weight delegated to each account in each DAO.
+	#[pallet::storage]
+	#[pallet::getter(fn delegated_weight)]
+	pub type DelegatedWeight<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Blake2_128Concat,
 		T::AccountId,
-		Vec<
-			VoteInfo<
-				T::DaoId,
-				T::ConcreteId,
-				T::Pledge,
-				u32,
-				BalanceOf<T>,
-				Opinion,
-				ReferendumIndex,
-			>,
-		>,
+		BalanceOf<T>,
 		ValueQuery,
 	>;
 
@@ -318,24 +549,184 @@ pub mod pallet {
 	pub type MinVoteWeightOf<T: Config> =
 		StorageDoubleMap<_, Identity, T::DaoId, Identity, T::CallId, BalanceOf<T>, ValueQuery>;
 
+	/// Minimum voting weight required for each external transaction from a given magnitude on.
+	///
+	/// A call whose magnitude is below every tier needs the weight in `MinVoteWeightOf`.
+	#[pallet::storage]
+	#[pallet::getter(fn min_vote_weight_tiers)]
+	pub type MinVoteWeightTiers<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::DaoId,
+		Identity,
+		T::CallId,
+		MinVoteWeightTiersOf<T>,
+		ValueQuery,
+	>;
+
+	/// The minimum voting weights of calls a template registers under a name, for DAOs to apply
+	/// at once.
+	#[pallet::storage]
+	#[pallet::getter(fn min_vote_weight_presets)]
+	pub type MinVoteWeightPresets<T: Config> =
+		StorageMap<_, Blake2_128Concat, PresetName, MinVoteWeightsOf<T>>;
+
+	/// The turnout and the share of ayes a referendum on each external transaction needs.
+	///
+	/// A call without a rule passes with as many ayes as nays.
+	#[pallet::storage]
+	#[pallet::getter(fn approval_rule_of)]
+	pub type ApprovalRuleOf<T: Config> =
+		StorageDoubleMap<_, Identity, T::DaoId, Identity, T::CallId, ApprovalRule>;
+
+	/// How the pledged weight of a vote counts in the tally of each DAO.
+	#[pallet::storage]
+	#[pallet::getter(fn voting_curve)]
+	pub type VotingCurveOf<T: Config> =
+		StorageMap<_, Identity, T::DaoId, VotingCurve, ValueQuery>;
+
+	/// The account each identity voted with in an ongoing referendum of a DAO whose voting curve
+	/// is quadratic.
+	#[pallet::storage]
+	#[pallet::getter(fn quadratic_voter)]
+	pub type QuadraticVoters<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, T::DaoId>,
+			NMapKey<Identity, ReferendumIndex>,
+			NMapKey<Blake2_128Concat, VoterIdentityOf<T>>,
+		),
+		T::AccountId,
+	>;
+
 	/// When the referendum was last launched.
 	#[pallet::storage]
 	#[pallet::getter(fn launch_tag)]
 	pub type LaunchTag<T: Config> = StorageMap<_, Identity, T::DaoId, u32, ValueQuery>;
 
+	/// DAOs whose next referendum is launched automatically at a given block.
+	#[pallet::storage]
+	#[pallet::getter(fn launches_due)]
+	pub type LaunchesDue<T: Config> = StorageMap<_, Identity, u32, Vec<T::DaoId>, ValueQuery>;
+
+	/// The block at which the next referendum of a DAO is launched automatically.
+	#[pallet::storage]
+	#[pallet::getter(fn next_launch)]
+	pub type NextLaunch<T: Config> = StorageMap<_, Identity, T::DaoId, u32>;
+
+	/// Referendums enacted automatically at a given block.
+	#[pallet::storage]
+	#[pallet::getter(fn enactments_due)]
+	pub type EnactmentsDue<T: Config> =
+		StorageMap<_, Identity, u32, Vec<(T::DaoId, ReferendumIndex)>, ValueQuery>;
+
+	/// Due launches over the per block limit, waiting to be launched.
+	#[pallet::storage]
+	#[pallet::getter(fn launches_overdue)]
+	pub type LaunchesOverdue<T: Config> = StorageValue<_, Vec<T::DaoId>, ValueQuery>;
+
+	/// Due referendums over the per block limit, waiting to be enacted.
+	#[pallet::storage]
+	#[pallet::getter(fn enactments_overdue)]
+	pub type EnactmentsOverdue<T: Config> =
+		StorageValue<_, Vec<(T::DaoId, ReferendumIndex)>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Launch the referendums and enact the matured referendums that are due, the overdue
+		/// ones first, at most `MaxScheduledPerBlock` of each.
+		///
+		/// The rest waits in `LaunchesOverdue` and `EnactmentsOverdue`.
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let now: u32 = n.saturated_into();
+			let limit = T::MaxScheduledPerBlock::get() as usize;
+			let mut weight = T::DbWeight::get().reads_writes(4, 4);
+
+			let mut launches = LaunchesOverdue::<T>::take();
+			launches.append(&mut LaunchesDue::<T>::take(now));
+			if launches.len() > limit {
+				LaunchesOverdue::<T>::put(launches.split_off(limit));
+			}
+			for dao_id in launches {
+				Self::launch_due(dao_id);
+				weight = weight.saturating_add(<T as Config>::WeightInfo::open_table());
+			}
+
+			let mut enactments = EnactmentsOverdue::<T>::take();
+			enactments.append(&mut EnactmentsDue::<T>::take(now));
+			if enactments.len() > limit {
+				EnactmentsOverdue::<T>::put(enactments.split_off(limit));
+			}
+			for (dao_id, index) in enactments {
+				let executed = Self::enact_due(dao_id, index);
+				weight = weight
+					.saturating_add(<T as Config>::WeightInfo::enact_proposal())
+					.saturating_add(executed);
+			}
+
+			weight
+		}
+
+		/// Launch and enact overdue referendums while the weight left in the block covers them and
+		/// their calls.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(2, 2);
+
+			let mut launches = LaunchesOverdue::<T>::take();
+			let mut launched = 0;
+			for dao_id in launches.iter().copied() {
+				let next = weight.saturating_add(<T as Config>::WeightInfo::open_table());
+				if next.any_gt(remaining_weight) {
+					break
+				}
+				Self::launch_due(dao_id);
+				weight = next;
+				launched += 1;
+			}
+			launches.drain(..launched);
+			if !launches.is_empty() {
+				LaunchesOverdue::<T>::put(launches);
+			}
+
+			let mut enactments = EnactmentsOverdue::<T>::take();
+			let mut enacted = 0;
+			for (dao_id, index) in enactments.iter().copied() {
+				let enact = weight.saturating_add(<T as Config>::WeightInfo::enact_proposal());
+				let call = Self::referendum_weight(dao_id, index);
+				if enact.saturating_add(call).any_gt(remaining_weight) {
+					break
+				}
+				weight = enact.saturating_add(Self::enact_due(dao_id, index));
+				enacted += 1;
+			}
+			enactments.drain(..enacted);
+			if !enactments.is_empty() {
+				EnactmentsOverdue::<T>::put(enactments);
+			}
+
+			weight
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// initiate a proposal.
-		Proposed(T::DaoId, T::Hash),
+		Proposed(T::DaoId, T::Hash, BoundedMetadataOf<T>),
 		/// Others support initiating proposals.
 		Second(T::DaoId, BalanceOf<T>),
+		/// A proposal was withdrawn by its proposer and the deposits returned.
+		Withdrawn { dao_id: T::DaoId, index: PropIndex },
 		/// Open a referendum.
 		StartTable(T::DaoId, ReferendumIndex),
 		/// Vote for the referendum.
 		Vote(T::DaoId, ReferendumIndex, T::Pledge),
 		/// Cancel a vote on a referendum.
 		CancelVote(T::DaoId, ReferendumIndex),
+		/// An account delegated its weight in DAO to another account.
+		Delegated { dao_id: T::DaoId, who: T::AccountId, target: T::AccountId },
+		/// An account revoked its delegation in DAO.
+		Undelegated { dao_id: T::DaoId, who: T::AccountId },
 		/// Vote and execute the transaction corresponding to the proposa.
 		EnactProposal { dao_id: T::DaoId, index: ReferendumIndex, result: DResult },
 		/// Unlock
@@ -356,6 +747,26 @@ pub mod pallet {
 		SetReservePeriod { dao_id: T::DaoId, period: u32 },
 		/// Set the time to delay the execution of the proposal.
 		SetEnactmentPeriod { dao_id: T::DaoId, period: u32 },
+		/// Set how the pledged weight of a vote counts in the tally.
+		SetVotingCurve { dao_id: T::DaoId, curve: VotingCurve },
+		/// Set the turnout and the share of ayes a call needs, or removed them if `None`.
+		SetApprovalRule { dao_id: T::DaoId, call_id: T::CallId, rule: Option<ApprovalRule> },
+		/// A minimum voting weight preset was registered, or removed if it has no weights.
+		MinVoteWeightPresetRegistered { name: PresetName, weights: MinVoteWeightsOf<T> },
+		/// A minimum voting weight preset was applied in DAO.
+		MinVoteWeightPresetApplied { dao_id: T::DaoId, name: PresetName },
+		/// Set the minimum voting weights of a call by its magnitude.
+		SetMinVoteWeightTiers {
+			dao_id: T::DaoId,
+			call_id: T::CallId,
+			tiers: MinVoteWeightTiersOf<T>,
+		},
+		/// An ongoing referendum was cancelled, and its deposits returned or slashed.
+		ReferendumCancelled { dao_id: T::DaoId, index: ReferendumIndex, slashed: bool },
+		/// A proposal hash was blacklisted in DAO until the given block.
+		Blacklisted { dao_id: T::DaoId, proposal_hash: T::Hash, until: u32 },
+		/// Set how long a blacklisted proposal can not be proposed again.
+		SetBlacklistPeriod { dao_id: T::DaoId, period: u32 },
 	}
 
 	// Errors inform users that something went wrong.
@@ -389,26 +800,77 @@ pub mod pallet {
 		VoteWeightTooLow,
 		///
 		PledgeNotEnough,
+		/// The title of the proposal is too long.
+		TitleTooLong,
+		/// The description hash of the proposal is too long.
+		DescriptionHashTooLong,
+		/// Account is not the proposer of the proposal.
+		NotProposer,
+		/// There are more than `MAX_MAGNITUDE_TIERS` tiers.
+		TooManyTiers,
+		/// The tiers are not in strictly ascending order of magnitude.
+		TiersNotAscending,
+		/// There are more than `MAX_PRESET_CALLS` calls.
+		TooManyCalls,
+		/// The name of the preset is too long.
+		PresetNameTooLong,
+		/// The preset does not exist.
+		PresetMissing,
+		/// The pledge is already counted in this referendum.
+		PledgeAlreadyVoted,
+		/// The account has no identity to vote as.
+		NoIdentity,
+		/// The identity has already voted in this referendum.
+		IdentityAlreadyVoted,
+		/// The votes cast do not reach the turnout the call needs.
+		TurnoutTooLow,
+		/// An account can not delegate to itself.
+		SelfDelegation,
+		/// The account has a delegation in DAO, which must be revoked and unlocked first.
+		AlreadyDelegating,
+		/// The account has no delegation in DAO to revoke.
+		NotDelegating,
+		/// The account votes through its delegation in DAO.
+		Delegating,
+		/// The account votes in an ongoing referendum of DAO.
+		AlreadyVoting,
+		/// A split vote has neither ayes nor nays.
+		EmptySplit,
+		/// The proposal is blacklisted.
+		ProposalBlacklisted,
+		/// The referendum is not about the blacklisted proposal.
+		ReferendumMismatch,
+		/// Delegations can not be chained.
+		DelegationChain,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// initiate a proposal.
+		///
+		/// The call must be noted in the preimage store of the DAO, it is only decoded when
+		/// the proposal is enacted.
+		///
+		/// `title` and `description_hash` (an IPFS CID) tell voters why the proposal exists.
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose())]
 		pub fn propose(
 			origin: OriginFor<T>,
 			dao_id: T::DaoId,
-			proposal: Box<<T as dao::Config>::Call>,
+			proposal: PreimageRef<T::Hash>,
 			#[pallet::compact] value: BalanceOf<T>,
+			title: Option<Vec<u8>>,
+			description_hash: Option<Vec<u8>>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(
-				dao::Pallet::<T>::try_get_concrete_id(dao_id)?.contains(*proposal.clone()),
-				dao::Error::<T>::InVailCall
-			);
+			let metadata = Self::try_bound_metadata(title, description_hash)?;
+			dao::Pallet::<T>::ensure_preimage(dao_id, &proposal)?;
 			ensure!(value >= MinimumDeposit::<T>::get(dao_id), Error::<T>::DepositTooLow);
 
-			let proposal_hash = T::Hashing::hash_of(&proposal);
+			let proposal_hash = proposal.hash;
+			if let Some(until) = Blacklist::<T>::get(dao_id, proposal_hash) {
+				ensure!(Self::now() >= until, Error::<T>::ProposalBlacklisted);
+				Blacklist::<T>::remove(dao_id, proposal_hash);
+			}
 			let index = Self::public_prop_count(dao_id);
 			let real_prop_count = PublicProps::<T>::decode_len(dao_id).unwrap_or(0) as u32;
 			let max_proposals = MaxPublicProps::<T>::get(dao_id);
@@ -419,9 +881,12 @@ pub mod pallet {
 			PublicPropCount::<T>::insert(dao_id, index + 1);
 			<DepositOf<T>>::insert(dao_id, index, (&[&who][..], value));
 
-			<PublicProps<T>>::append(dao_id, (index, proposal_hash, *proposal, who));
+			dao::Pallet::<T>::request_preimage(dao_id, &proposal)?;
+			<PublicProps<T>>::append(dao_id, (index, proposal, who));
+			<ProposalMetadataOf<T>>::insert(dao_id, index, metadata.clone());
+			Self::schedule_launch(dao_id);
 
-			Self::deposit_event(Event::<T>::Proposed(dao_id, proposal_hash));
+			Self::deposit_event(Event::<T>::Proposed(dao_id, proposal_hash, metadata));
 			Ok(().into())
 		}
 
@@ -442,26 +907,46 @@ pub mod pallet {
 			let unreserved_block = Self::now()
 				.checked_add(ReservePeriod::<T>::get(dao_id))
 				.ok_or(Error::<T>::Overflow)?;
-			ReserveOf::<T>::append(who, (deposit_amount, unreserved_block));
+			ReserveOf::<T>::append(who, (deposit_amount, unreserved_block, (dao_id, proposal)));
 			Self::deposit_event(Event::<T>::Second(dao_id, deposit_amount));
 
 			Ok(().into())
 		}
 
+		/// The proposer takes back a proposal that has not been launched yet.
+		///
+		/// The deposits of the proposer and of everyone who seconded it are returned.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw())]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			#[pallet::compact] proposal: PropIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut public_props = Self::public_props(dao_id);
+			let position = public_props
+				.iter()
+				.position(|p| p.0 == proposal)
+				.ok_or(Error::<T>::ProposalMissing)?;
+			ensure!(public_props[position].2 == who, Error::<T>::NotProposer);
+			let (_, preimage, _) = public_props.swap_remove(position);
+			<PublicProps<T>>::insert(dao_id, public_props);
+			dao::Pallet::<T>::unrequest_preimage(dao_id, preimage.hash);
+			<ProposalMetadataOf<T>>::remove(dao_id, proposal);
+
+			if let Some((depositors, amount)) = <DepositOf<T>>::take(dao_id, proposal) {
+				Self::settle_deposits(dao_id, proposal, depositors, amount, false);
+			}
+			Self::deposit_event(Event::<T>::Withdrawn { dao_id, index: proposal });
+
+			Ok(().into())
+		}
+
 		/// Open a referendum.
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::open_table())]
 		pub fn open_table(origin: OriginFor<T>, dao_id: T::DaoId) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
-			let tag = LaunchTag::<T>::get(dao_id);
-			let now = Self::now();
-			let dao_start_time = dao::Pallet::<T>::try_get_dao(dao_id)?.start_block;
-			// (now - dao_start_time) / LaunchPeriod > tag
-			ensure!(
-				tag.checked_mul(LaunchPeriod::<T>::get(dao_id)).ok_or(Error::<T>::Overflow)? <
-					(now - dao_start_time),
-				Error::<T>::NotTableTime
-			);
-			let index = Self::launch_public(dao_id)?;
+			let index = Self::do_open_table(dao_id)?;
 			Self::deposit_event(Event::<T>::StartTable(dao_id, index));
 
 			Ok(().into())
@@ -478,6 +963,7 @@ pub mod pallet {
 			opinion: Opinion,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			ensure!(opinion != Opinion::Split { aye: 0, nay: 0 }, Error::<T>::EmptySplit);
 			let now = Self::now();
 			let mut vote_weight = BalanceOf::<T>::from(0u32);
 
@@ -489,17 +975,33 @@ pub mod pallet {
 					if let ReferendumInfo::Ongoing(ref mut x) = info {
 						if x.end > now {
 							let concrete_id = dao::Pallet::<T>::try_get_concrete_id(dao_id)?;
+							let delegation = Delegations::<T>::get(&who, dao_id);
+							ensure!(
+								!matches!(delegation, Some(d) if d.unlock_block.is_none()),
+								Error::<T>::Delegating
+							);
+							let votes = VotesOf::<T>::get(&who);
+							let mut votes = votes
+								.iter()
+								.filter(|h| h.dao_id == dao_id && h.referendum_index == index);
+							ensure!(
+								!votes.clone().any(|h| h.pledge.overlaps(&pledge)),
+								Error::<T>::PledgeAlreadyVoted
+							);
+							// The delegated weight is counted once, along with the first vote.
+							let delegated_weight = match votes.next() {
+								Some(_) => Zero::zero(),
+								None => VotingCurveOf::<T>::get(dao_id)
+									.tally_weight(DelegatedWeight::<T>::get(dao_id, &who)),
+							};
+							let curve = VotingCurveOf::<T>::get(dao_id);
+							if curve == VotingCurve::Quadratic {
+								Self::try_vote_as_identity(&who, dao_id, index)?;
+							}
 							let vote_result = pledge.try_vote(&who, &dao_id, &conviction)?;
-							vote_weight = vote_result.0;
+							vote_weight = curve.tally_weight(vote_result.0);
 							let duration = vote_result.1;
-							match opinion {
-								Opinion::NAYS => {
-									x.tally.nays += vote_weight;
-								},
-								Opinion::AYES => {
-									x.tally.ayes += vote_weight;
-								},
-							};
+							x.tally.add(&opinion, vote_weight.saturating_add(delegated_weight));
 							VotesOf::<T>::append(
 								&who,
 								VoteInfo {
@@ -508,7 +1010,8 @@ pub mod pallet {
 									pledge,
 									opinion,
 									vote_weight,
-									unlock_block: now + duration,
+									delegated_weight,
+									unlock_block: (now + duration).max(x.end),
 									referendum_index: index,
 								},
 							);
@@ -543,27 +1046,29 @@ pub mod pallet {
 					let now = Self::now();
 					if let ReferendumInfo::Ongoing(ref mut x) = info {
 						if x.end > now {
-							let mut votes = VotesOf::<T>::get(&who);
-							votes.retain(|h| {
-								if h.referendum_index == index &&
-									h.pledge.vote_end_do(&who, &dao_id).is_ok()
-								{
-									match h.opinion {
-										Opinion::NAYS => {
-											x.tally.nays =
-												x.tally.nays.saturating_sub(h.vote_weight);
-										},
-										_ => {
-											x.tally.ayes =
-												x.tally.ayes.saturating_sub(h.vote_weight);
-										},
-									};
-									false
-								} else {
-									true
-								}
+							let ended = Self::end_votes(&who, |h| {
+								h.dao_id == dao_id && h.referendum_index == index
 							});
-							VotesOf::<T>::insert(&who, votes);
+							// the identity is free again only once the vote has ended.
+							let identity = if ended.is_empty() {
+								None
+							} else {
+								T::VoterIdentity::identity_of(&who)
+							};
+							for h in ended {
+								let weight = h.vote_weight.saturating_add(h.delegated_weight);
+								x.tally.remove(&h.opinion, weight);
+							}
+							if let Some(identity) = identity {
+								QuadraticVoters::<T>::mutate_exists(
+									(dao_id, index, identity),
+									|voter| {
+										if voter.as_ref() == Some(&who) {
+											*voter = None;
+										}
+									},
+								);
+							}
 						} else {
 							return Err(Error::<T>::VoteEnd)?
 						}
@@ -579,7 +1084,85 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Delegate the weight of a pledge in DAO to `target`, which counts along with the votes
+		/// of `target` in every referendum.
+		///
+		/// The pledge is locked as with a vote, and the account can not vote in DAO itself
+		/// until the delegation is revoked. Delegations can not be chained.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			target: T::AccountId,
+			pledge: T::Pledge,
+			conviction: T::Conviction,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(who != target, Error::<T>::SelfDelegation);
+			ensure!(!Delegations::<T>::contains_key(&who, dao_id), Error::<T>::AlreadyDelegating);
+			// the weight delegated to an account only counts along with its own votes.
+			ensure!(!DelegatedWeight::<T>::contains_key(dao_id, &who), Error::<T>::DelegationChain);
+			let target_delegates =
+				Delegations::<T>::get(&target, dao_id).is_some_and(|d| d.unlock_block.is_none());
+			ensure!(!target_delegates, Error::<T>::DelegationChain);
+			let now = Self::now();
+			ensure!(
+				!VotesOf::<T>::get(&who)
+					.iter()
+					.filter(|h| h.dao_id == dao_id)
+					.any(|h| Self::is_ongoing(dao_id, h.referendum_index, now)),
+				Error::<T>::AlreadyVoting
+			);
+			let concrete_id = dao::Pallet::<T>::try_get_concrete_id(dao_id)?;
+			let (vote_weight, lock_duration) = pledge.try_vote(&who, &dao_id, &conviction)?;
+
+			Delegations::<T>::insert(
+				&who,
+				dao_id,
+				Delegation {
+					target: target.clone(),
+					concrete_id,
+					pledge,
+					conviction,
+					vote_weight,
+					lock_duration,
+					unlock_block: None,
+				},
+			);
+			DelegatedWeight::<T>::mutate(dao_id, &target, |w| *w = w.saturating_add(vote_weight));
+			Self::update_delegated_votes(dao_id, &target);
+			Self::deposit_event(Event::<T>::Delegated { dao_id, who, target });
+
+			Ok(().into())
+		}
+
+		/// Revoke the delegation in DAO.
+		///
+		/// The weight stops counting in ongoing referendums, and the pledge stays locked for the
+		/// lock periods of the conviction, then `unlock` releases it.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, dao_id: T::DaoId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let now = Self::now();
+			let target = Delegations::<T>::try_mutate(&who, dao_id, |d| {
+				let d = d.as_mut().filter(|d| d.unlock_block.is_none());
+				let d = d.ok_or(Error::<T>::NotDelegating)?;
+				d.unlock_block = Some(now.saturating_add(d.lock_duration));
+				DelegatedWeight::<T>::mutate_exists(dao_id, &d.target, |w| {
+					*w = w.map(|w| w.saturating_sub(d.vote_weight)).filter(|w| !w.is_zero());
+				});
+				Ok::<_, DispatchError>(d.target.clone())
+			})?;
+			Self::update_delegated_votes(dao_id, &target);
+			Self::deposit_event(Event::<T>::Undelegated { dao_id, who });
+
+			Ok(().into())
+		}
+
 		/// Vote and execute the transaction corresponding to the proposa
+		///
+		/// A referendum that did not pass, such as one short of the turnout its call needs, is
+		/// finished as not approved, with the reason in `EnactProposal`.
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::enact_proposal())]
 		pub fn enact_proposal(
 			origin: OriginFor<T>,
@@ -587,58 +1170,7 @@ pub mod pallet {
 			index: ReferendumIndex,
 		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
-			let now = Self::now();
-			let mut approved = false;
-			let info =
-				ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
-			match info {
-				ReferendumInfo::Ongoing(x) =>
-					if x.end > now {
-						return Err(Error::<T>::VoteNotEnd)?
-					} else if x.end.saturating_add(x.delay) > now {
-						return Err(Error::<T>::InDelayTime)?
-					} else {
-						{
-							let call_id: T::CallId =
-								TryFrom::<<T as dao::Config>::Call>::try_from(x.proposal.clone())
-									.unwrap_or_default();
-
-							if x.tally.ayes.saturating_add(x.tally.nays) >=
-								MinVoteWeightOf::<T>::get(dao_id, call_id)
-							{
-								if x.tally.ayes >= x.tally.nays {
-									approved = true;
-									let res = x.proposal.dispatch_bypass_filter(
-										frame_system::RawOrigin::Signed(
-											dao::Pallet::<T>::try_get_dao_account_id(dao_id)?,
-										)
-										.into(),
-									);
-									Self::deposit_event(Event::EnactProposal {
-										dao_id,
-										index,
-										result: res.map(|_| ()).map_err(|e| e.error),
-									});
-								} else {
-									Self::deposit_event(Event::EnactProposal {
-										dao_id,
-										index,
-										result: Err(Error::<T>::VoteEndButNotPass)?,
-									});
-								}
-							} else {
-								return Err(Error::<T>::VoteWeightTooLow)?
-							}
-						}
-					},
-				_ => return Err(Error::<T>::ReferendumFinished)?,
-			}
-			ReferendumInfoOf::<T>::insert(
-				dao_id,
-				index,
-				ReferendumInfo::Finished { approved, end: now },
-			);
-
+			Self::do_enact_proposal(dao_id, index)?;
 			Ok(().into())
 		}
 
@@ -666,20 +1198,25 @@ pub mod pallet {
 
 			//
 			{
-				let mut votes = VotesOf::<T>::get(&who);
-				votes.retain(|h| {
-					if h.unlock_block > now || h.pledge.vote_end_do(&who, &h.dao_id).is_err() {
-						true
-					} else {
-						Self::deposit_event(Event::<T>::Unlock(
-							who.clone(),
-							h.concrete_id,
-							h.pledge,
-						));
-						false
+				for h in Self::end_votes(&who, |h| h.unlock_block <= now) {
+					Self::deposit_event(Event::<T>::Unlock(who.clone(), h.concrete_id, h.pledge));
+				}
+			}
+
+			//
+			{
+				for (dao_id, d) in Delegations::<T>::iter_prefix(&who) {
+					match d.unlock_block {
+						Some(unlock_block) if unlock_block <= now => {},
+						_ => continue,
 					}
-				});
-				VotesOf::<T>::insert(&who, votes);
+					let held = Self::held_pledges(&who, Some(dao_id));
+					if d.pledge.vote_end_do(&who, &dao_id, &held).is_ok() {
+						Delegations::<T>::remove(&who, dao_id);
+						let event = Event::<T>::Unlock(who.clone(), d.concrete_id, d.pledge);
+						Self::deposit_event(event);
+					}
+				}
 			}
 
 			Ok(().into())
@@ -797,10 +1334,291 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// call id:308
+		///
+		/// Set the minimum voting weights a call needs from a given magnitude on, such as the
+		/// amount of a spend.
+		///
+		/// `tiers` must be in strictly ascending order of magnitude. An empty list removes them.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_min_vote_weight_tiers())]
+		pub fn set_min_vote_weight_tiers(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			call_id: T::CallId,
+			tiers: Vec<(u128, BalanceOf<T>)>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			ensure!(tiers.windows(2).all(|w| w[0].0 < w[1].0), Error::<T>::TiersNotAscending);
+			let tiers: MinVoteWeightTiersOf<T> =
+				tiers.try_into().map_err(|_| Error::<T>::TooManyTiers)?;
+
+			if tiers.is_empty() {
+				MinVoteWeightTiers::<T>::remove(dao_id, call_id);
+			} else {
+				MinVoteWeightTiers::<T>::insert(dao_id, call_id, tiers.clone());
+			}
+			Self::deposit_event(Event::<T>::SetMinVoteWeightTiers { dao_id, call_id, tiers });
+
+			Ok(().into())
+		}
+
+		/// call id:309
+		///
+		/// Set the minimum voting weights of some calls at once.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_min_vote_weights())]
+		pub fn set_min_vote_weights(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			weights: Vec<(T::CallId, BalanceOf<T>)>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			let weights: MinVoteWeightsOf<T> =
+				weights.try_into().map_err(|_| Error::<T>::TooManyCalls)?;
+
+			Self::do_set_min_vote_weights(dao_id, weights);
+			Ok(().into())
+		}
+
+		/// call id:311
+		///
+		/// Set how the pledged weight of a vote counts in the tally.
+		///
+		/// It applies to the votes cast from then on.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_voting_curve())]
+		pub fn set_voting_curve(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			curve: VotingCurve,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			VotingCurveOf::<T>::insert(dao_id, curve);
+			Self::deposit_event(Event::<T>::SetVotingCurve { dao_id, curve });
+
+			Ok(().into())
+		}
+
+		/// call id:312
+		///
+		/// Set the turnout and the share of ayes a call needs, such as 66% approval at 20%
+		/// turnout for a constitution change.
+		///
+		/// `None` removes them, so that the call passes with as many ayes as nays.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_approval_rule())]
+		pub fn set_approval_rule(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			call_id: T::CallId,
+			rule: Option<ApprovalRule>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			ApprovalRuleOf::<T>::set(dao_id, call_id, rule);
+			Self::deposit_event(Event::<T>::SetApprovalRule { dao_id, call_id, rule });
+
+			Ok(().into())
+		}
+
+		/// Register the minimum voting weights of some calls under a name, such as
+		/// `conservative` or `fast`.
+		///
+		/// The template does this with the root origin. An empty list removes the preset.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_min_vote_weight_preset())]
+		pub fn register_min_vote_weight_preset(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			weights: Vec<(T::CallId, BalanceOf<T>)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let name: PresetName = name.try_into().map_err(|_| Error::<T>::PresetNameTooLong)?;
+			let weights: MinVoteWeightsOf<T> =
+				weights.try_into().map_err(|_| Error::<T>::TooManyCalls)?;
+
+			if weights.is_empty() {
+				MinVoteWeightPresets::<T>::remove(&name);
+			} else {
+				MinVoteWeightPresets::<T>::insert(&name, weights.clone());
+			}
+			Self::deposit_event(Event::<T>::MinVoteWeightPresetRegistered { name, weights });
+
+			Ok(().into())
+		}
+
+		/// call id:310
+		///
+		/// Set the minimum voting weights of a preset the template registered.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::apply_min_vote_weight_preset())]
+		pub fn apply_min_vote_weight_preset(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			let name: PresetName = name.try_into().map_err(|_| Error::<T>::PresetNameTooLong)?;
+			let weights =
+				MinVoteWeightPresets::<T>::get(&name).ok_or(Error::<T>::PresetMissing)?;
+
+			Self::do_set_min_vote_weights(dao_id, weights);
+			Self::deposit_event(Event::<T>::MinVoteWeightPresetApplied { dao_id, name });
+
+			Ok(().into())
+		}
+
+		/// call id:313
+		///
+		/// Cancel an ongoing referendum and return its deposits.
+		///
+		/// Who can cancel is up to the origin the DAO sets for this call, such as a committee of
+		/// the agency or the emergency members.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_referendum())]
+		pub fn cancel_referendum(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			#[pallet::compact] index: ReferendumIndex,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			Self::do_cancel_referendum(dao_id, index, false)?;
+
+			Ok(().into())
+		}
+
+		/// call id:314
+		///
+		/// Block a proposal hash for `BlacklistPeriod` blocks.
+		///
+		/// Public proposals with this hash are removed, and so is the referendum `maybe_ref_index`
+		/// if it is about this hash. Their deposits are slashed.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::blacklist_proposal())]
+		pub fn blacklist_proposal(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			proposal_hash: T::Hash,
+			maybe_ref_index: Option<ReferendumIndex>,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			if let Some(index) = maybe_ref_index {
+				match ReferendumInfoOf::<T>::get(dao_id, index) {
+					Some(ReferendumInfo::Ongoing(x)) => ensure!(
+						x.proposal.hash == proposal_hash,
+						Error::<T>::ReferendumMismatch
+					),
+					Some(_) => return Err(Error::<T>::ReferendumFinished)?,
+					None => return Err(Error::<T>::ReferendumNotExists)?,
+				}
+				Self::do_cancel_referendum(dao_id, index, true)?;
+			}
+
+			let mut public_props = Self::public_props(dao_id);
+			public_props.retain(|(index, proposal, _)| {
+				if proposal.hash != proposal_hash {
+					return true
+				}
+				<ProposalMetadataOf<T>>::remove(dao_id, index);
+				if let Some((depositors, amount)) = <DepositOf<T>>::take(dao_id, index) {
+					Self::settle_deposits(dao_id, *index, depositors, amount, true);
+				}
+				dao::Pallet::<T>::unrequest_preimage(dao_id, proposal_hash);
+				false
+			});
+			<PublicProps<T>>::insert(dao_id, public_props);
+
+			let until = Self::now().saturating_add(BlacklistPeriod::<T>::get(dao_id));
+			Blacklist::<T>::insert(dao_id, proposal_hash, until);
+			Self::deposit_event(Event::<T>::Blacklisted { dao_id, proposal_hash, until });
+
+			Ok(().into())
+		}
+
+		/// call id:315
+		///
+		/// Set how long a blacklisted proposal can not be proposed again.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_blacklist_period())]
+		pub fn set_blacklist_period(
+			origin: OriginFor<T>,
+			dao_id: T::DaoId,
+			period: u32,
+		) -> DispatchResultWithPostInfo {
+			dao::Pallet::<T>::ensrue_dao_root(origin, dao_id)?;
+			BlacklistPeriod::<T>::insert(dao_id, period);
+			Self::deposit_event(Event::<T>::SetBlacklistPeriod { dao_id, period });
+
+			Ok(().into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_set_min_vote_weights(dao_id: T::DaoId, weights: MinVoteWeightsOf<T>) {
+		for (call_id, min_vote_weight) in weights {
+			MinVoteWeightOf::<T>::insert(dao_id, call_id, min_vote_weight);
+			Self::deposit_event(Event::<T>::SetMinVoteWeight(dao_id, call_id, min_vote_weight));
+		}
+	}
+
+	/// The minimum voting weight a call needs.
+	///
+	/// The highest tier the magnitude of the call reaches applies, otherwise the weight of the
+	/// call.
+	pub fn min_vote_weight_for(dao_id: T::DaoId, call: &<T as dao::Config>::Call) -> BalanceOf<T> {
+		let call_id: T::CallId =
+			TryFrom::<<T as dao::Config>::Call>::try_from(call.clone()).unwrap_or_default();
+		if let Some(magnitude) = <T as dao::Config>::CallMagnitude::magnitude(call) {
+			let tier = MinVoteWeightTiers::<T>::get(dao_id, call_id)
+				.into_iter()
+				.rev()
+				.find(|(from, _)| magnitude >= *from);
+			if let Some((_, weight)) = tier {
+				return weight
+			}
+		}
+		MinVoteWeightOf::<T>::get(dao_id, call_id)
+	}
+
+	/// The turnout and the share of ayes a call needs, if it has a rule.
+	pub fn approval_rule_for(
+		dao_id: T::DaoId,
+		call: &<T as dao::Config>::Call,
+	) -> Option<ApprovalRule> {
+		let call_id: T::CallId =
+			TryFrom::<<T as dao::Config>::Call>::try_from(call.clone()).unwrap_or_default();
+		ApprovalRuleOf::<T>::get(dao_id, call_id)
+	}
+
+	/// The title and description of a public proposal, in the form used by the runtime api.
+	pub fn proposal_metadata(
+		dao_id: T::DaoId,
+		index: PropIndex,
+	) -> Option<ProposalMetadata<Vec<u8>, Vec<u8>>> {
+		ProposalMetadataOf::<T>::get(dao_id, index).map(Self::unbound_metadata)
+	}
+
+	/// The title and description of an ongoing referendum, in the form used by the runtime api.
+	pub fn referendum_metadata(
+		dao_id: T::DaoId,
+		index: ReferendumIndex,
+	) -> Option<ProposalMetadata<Vec<u8>, Vec<u8>>> {
+		ReferendumMetadataOf::<T>::get(dao_id, index).map(Self::unbound_metadata)
+	}
+
+	fn unbound_metadata(metadata: BoundedMetadataOf<T>) -> ProposalMetadata<Vec<u8>, Vec<u8>> {
+		ProposalMetadata {
+			title: metadata.title.map(Into::into),
+			description_hash: metadata.description_hash.map(Into::into),
+		}
+	}
+
+	fn try_bound_metadata(
+		title: Option<Vec<u8>>,
+		description_hash: Option<Vec<u8>>,
+	) -> result::Result<BoundedMetadataOf<T>, DispatchError> {
+		let title = title
+			.map(|t| BoundedVec::try_from(t).map_err(|_| Error::<T>::TitleTooLong))
+			.transpose()?;
+		let description_hash = description_hash
+			.map(|d| BoundedVec::try_from(d).map_err(|_| Error::<T>::DescriptionHashTooLong))
+			.transpose()?;
+		Ok(ProposalMetadata { title, description_hash })
+	}
+
 	pub fn backing_for(dao_id: T::DaoId, proposal: PropIndex) -> Option<BalanceOf<T>> {
 		Self::deposit_of(dao_id, proposal).map(|(l, d)| d.saturating_mul((l.len() as u32).into()))
 	}
@@ -813,25 +1631,235 @@ impl<T: Config> Pallet<T> {
 			.max_by_key(|x| Self::backing_for(dao_id, (x.1).0).defensive_unwrap_or_else(Zero::zero))
 		{
 			let now = Self::now();
-			let (prop_index, _, proposal, _) = public_props.swap_remove(winner_index);
+			let (prop_index, proposal, _) = public_props.swap_remove(winner_index);
 			<PublicProps<T>>::insert(dao_id, public_props);
 
-			if <DepositOf<T>>::take(dao_id, prop_index).is_some() {
-				return Ok(Self::inject_referendum(
+			let metadata = <ProposalMetadataOf<T>>::take(dao_id, prop_index);
+			if let Some(deposit) = <DepositOf<T>>::take(dao_id, prop_index) {
+				let ref_index = Self::inject_referendum(
 					dao_id,
 					now.saturating_add(VotingPeriod::<T>::get(dao_id)),
 					proposal,
 					EnactmentPeriod::<T>::get(dao_id),
-				))
+				);
+				let (depositors, amount) = deposit;
+				let deposit = (prop_index, depositors, amount);
+				<ReferendumDepositOf<T>>::insert(dao_id, ref_index, deposit);
+				if let Some(metadata) = metadata {
+					<ReferendumMetadataOf<T>>::insert(dao_id, ref_index, metadata);
+				}
+				return Ok(ref_index)
 			}
+			dao::Pallet::<T>::unrequest_preimage(dao_id, proposal.hash);
 		}
 		Err(Error::<T>::NoneWaiting)?
 	}
 
+	fn do_open_table(dao_id: T::DaoId) -> result::Result<ReferendumIndex, DispatchError> {
+		let tag = LaunchTag::<T>::get(dao_id);
+		let now = Self::now();
+		let dao_start_time = dao::Pallet::<T>::try_get_dao(dao_id)?.start_block;
+		let period = LaunchPeriod::<T>::get(dao_id);
+		// (now - dao_start_time) / LaunchPeriod > tag
+		ensure!(
+			tag.checked_mul(period).ok_or(Error::<T>::Overflow)? <
+				now.saturating_sub(dao_start_time),
+			Error::<T>::NotTableTime
+		);
+		let index = Self::launch_public(dao_id)?;
+		// one referendum per launch period.
+		let elapsed = now.saturating_sub(dao_start_time);
+		LaunchTag::<T>::insert(dao_id, elapsed.checked_div(period).unwrap_or(elapsed) + 1);
+		Ok(index)
+	}
+
+	/// Launch the next referendum of a DAO automatically, if it has public proposals and
+	/// nothing is scheduled yet.
+	fn schedule_launch(dao_id: T::DaoId) {
+		if NextLaunch::<T>::contains_key(dao_id) || Self::public_props(dao_id).is_empty() {
+			return
+		}
+		let dao_start_time = match dao::Pallet::<T>::try_get_dao(dao_id) {
+			Ok(dao) => dao.start_block,
+			Err(_) => return,
+		};
+		let at = LaunchTag::<T>::get(dao_id)
+			.saturating_mul(LaunchPeriod::<T>::get(dao_id))
+			.saturating_add(dao_start_time)
+			.saturating_add(1)
+			.max(Self::now().saturating_add(1));
+		NextLaunch::<T>::insert(dao_id, at);
+		LaunchesDue::<T>::append(at, dao_id);
+	}
+
+	/// Finish a referendum whose voting has ended, returning the weight of the call it
+	/// dispatched.
+	fn do_enact_proposal(
+		dao_id: T::DaoId,
+		index: ReferendumIndex,
+	) -> Result<Weight, DispatchError> {
+		let now = Self::now();
+		let info = ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
+		let status = match info {
+			ReferendumInfo::Ongoing(x) => x,
+			_ => return Err(Error::<T>::ReferendumFinished)?,
+		};
+		ensure!(status.end <= now, Error::<T>::VoteNotEnd);
+		ensure!(status.end.saturating_add(status.delay) <= now, Error::<T>::InDelayTime);
+
+		// A referendum that did not pass is finished all the same, with the reason in the event.
+		let passed = Self::passed_call(dao_id, &status);
+		let approved = passed.is_ok();
+		let mut weight = Weight::zero();
+		let result = passed.and_then(|call| {
+			weight = call.get_dispatch_info().weight;
+			Self::dispatch_as_dao(dao_id, call)
+		});
+		Self::deposit_event(Event::EnactProposal { dao_id, index, result });
+		dao::Pallet::<T>::unrequest_preimage(dao_id, status.proposal.hash);
+		ReferendumInfoOf::<T>::insert(dao_id, index, ReferendumInfo::Finished { approved, end: now });
+		ReferendumMetadataOf::<T>::remove(dao_id, index);
+		let _ = QuadraticVoters::<T>::clear_prefix((dao_id, index), u32::MAX, None);
+		// the proposer gets the deposit back, seconders wait for `ReservePeriod`.
+		if let Some((_, depositors, amount)) = <ReferendumDepositOf<T>>::take(dao_id, index) {
+			if let Some(proposer) = depositors.first() {
+				T::Currency::unreserve(proposer, amount);
+			}
+		}
+
+		Ok(weight)
+	}
+
+	/// Launch the next referendum of a DAO that is due, and schedule the one after.
+	fn launch_due(dao_id: T::DaoId) {
+		NextLaunch::<T>::remove(dao_id);
+		// a DAO without public proposals has nothing to launch this time.
+		if let Ok(index) = Self::do_open_table(dao_id) {
+			Self::deposit_event(Event::<T>::StartTable(dao_id, index));
+		}
+		Self::schedule_launch(dao_id);
+	}
+
+	/// Enact a referendum that is due, unless it was finished already.
+	///
+	/// Returns the weight of the call it dispatched, if any.
+	fn enact_due(dao_id: T::DaoId, index: ReferendumIndex) -> Weight {
+		if !matches!(ReferendumInfoOf::<T>::get(dao_id, index), Some(ReferendumInfo::Ongoing(_))) {
+			return Weight::zero()
+		}
+		Self::do_enact_proposal(dao_id, index).unwrap_or_else(|_| {
+			// not matured yet, try again in the next block.
+			EnactmentsDue::<T>::append(Self::now().saturating_add(1), (dao_id, index));
+			Weight::zero()
+		})
+	}
+
+	/// The declared weight of the call of an ongoing referendum, zero if it can not be read.
+	fn referendum_weight(dao_id: T::DaoId, index: ReferendumIndex) -> Weight {
+		match ReferendumInfoOf::<T>::get(dao_id, index) {
+			Some(ReferendumInfo::Ongoing(status)) =>
+				dao::Pallet::<T>::try_get_preimage_call::<<T as dao::Config>::Call>(
+					dao_id,
+					&status.proposal,
+				)
+				.map(|call| call.get_dispatch_info().weight)
+				.unwrap_or_else(|_| Weight::zero()),
+			_ => Weight::zero(),
+		}
+	}
+
+	/// The call of a referendum whose voting has ended, if the referendum passed.
+	fn passed_call(
+		dao_id: T::DaoId,
+		status: &ReferendumStatus<u32, PreimageRef<T::Hash>, BalanceOf<T>>,
+	) -> Result<<T as dao::Config>::Call, DispatchError> {
+		let call: <T as dao::Config>::Call =
+			dao::Pallet::<T>::try_get_preimage_call(dao_id, &status.proposal)?;
+		let turnout = status.tally.turnout();
+		ensure!(turnout >= Self::min_vote_weight_for(dao_id, &call), Error::<T>::VoteWeightTooLow);
+		let rule = Self::approval_rule_for(dao_id, &call);
+		if let Some(rule) = rule {
+			let reached = match T::Pledge::total_voting_supply(&dao_id) {
+				Some(supply) => rule.turnout_reached(turnout, supply),
+				// An unknown supply only meets a zero turnout.
+				None => rule.turnout.is_zero(),
+			};
+			ensure!(reached, Error::<T>::TurnoutTooLow);
+		}
+		let approved = match rule {
+			Some(rule) => rule.is_approved(status.tally.ayes, status.tally.nays),
+			None => status.tally.ayes >= status.tally.nays,
+		};
+		ensure!(approved, Error::<T>::VoteEndButNotPass);
+		Ok(call)
+	}
+
+	fn dispatch_as_dao(dao_id: T::DaoId, call: <T as dao::Config>::Call) -> DResult {
+		let concrete_id = dao::Pallet::<T>::try_get_concrete_id(dao_id)?;
+		ensure!(concrete_id.contains(call.clone()), dao::Error::<T>::InVailCall);
+		let dao_account = dao::Pallet::<T>::try_get_dao_account_id(dao_id)?;
+		call.dispatch_bypass_filter(frame_system::RawOrigin::Signed(dao_account).into())
+			.map(|_| ())
+			.map_err(|e| e.error)
+	}
+
+	fn do_cancel_referendum(dao_id: T::DaoId, index: ReferendumIndex, slash: bool) -> DResult {
+		let info =
+			ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
+		let status = match info {
+			ReferendumInfo::Ongoing(x) => x,
+			_ => return Err(Error::<T>::ReferendumFinished)?,
+		};
+		dao::Pallet::<T>::unrequest_preimage(dao_id, status.proposal.hash);
+		let finished = ReferendumInfo::Finished { approved: false, end: Self::now() };
+		ReferendumInfoOf::<T>::insert(dao_id, index, finished);
+		ReferendumMetadataOf::<T>::remove(dao_id, index);
+		let _ = QuadraticVoters::<T>::clear_prefix((dao_id, index), u32::MAX, None);
+		if let Some((prop_index, depositors, amount)) =
+			<ReferendumDepositOf<T>>::take(dao_id, index)
+		{
+			Self::settle_deposits(dao_id, prop_index, depositors, amount, slash);
+		}
+		Self::deposit_event(Event::<T>::ReferendumCancelled { dao_id, index, slashed: slash });
+		Ok(())
+	}
+
+	/// Return or slash the deposits of a proposal.
+	///
+	/// The first depositor is the proposer. Seconders are settled now instead of waiting for
+	/// `ReservePeriod`, taking the reservation they made for this very proposal.
+	fn settle_deposits(
+		dao_id: T::DaoId,
+		index: PropIndex,
+		depositors: Vec<T::AccountId>,
+		amount: BalanceOf<T>,
+		slash: bool,
+	) {
+		let settle = |who: &T::AccountId| {
+			if slash {
+				let _ = T::Currency::slash_reserved(who, amount);
+			} else {
+				T::Currency::unreserve(who, amount);
+			}
+		};
+		let mut depositors = depositors.into_iter();
+		if let Some(proposer) = depositors.next() {
+			settle(&proposer);
+		}
+		for seconder in depositors {
+			ReserveOf::<T>::mutate(&seconder, |reserves| {
+				if let Some(pos) = reserves.iter().rposition(|r| r.2 == (dao_id, index)) {
+					reserves.remove(pos);
+					settle(&seconder);
+				}
+			});
+		}
+	}
+
 	fn inject_referendum(
 		dao_id: T::DaoId,
 		end: u32,
-		proposal: <T as dao::Config>::Call,
+		proposal: PreimageRef<T::Hash>,
 		delay: u32,
 	) -> ReferendumIndex {
 		let ref_index = Self::referendum_count(dao_id);
@@ -840,13 +1868,99 @@ impl<T: Config> Pallet<T> {
 
 		let item = ReferendumInfo::Ongoing(status);
 		<ReferendumInfoOf<T>>::insert(dao_id, ref_index, item);
+		<EnactmentsDue<T>>::append(end.saturating_add(delay), (dao_id, ref_index));
 		ref_index
 	}
 
+	/// Record that `who` votes in a referendum as its identity, which must not have voted yet.
+	fn try_vote_as_identity(
+		who: &T::AccountId,
+		dao_id: T::DaoId,
+		index: ReferendumIndex,
+	) -> DResult {
+		let identity = T::VoterIdentity::identity_of(who).ok_or(Error::<T>::NoIdentity)?;
+		ensure!(
+			!QuadraticVoters::<T>::contains_key((dao_id, index, &identity)),
+			Error::<T>::IdentityAlreadyVoted
+		);
+		QuadraticVoters::<T>::insert((dao_id, index, identity), who);
+		Ok(())
+	}
+
+	/// Whether the referendum can still be voted on.
+	fn is_ongoing(dao_id: T::DaoId, index: ReferendumIndex, now: u32) -> bool {
+		matches!(
+			ReferendumInfoOf::<T>::get(dao_id, index),
+			Some(ReferendumInfo::Ongoing(x)) if x.end > now
+		)
+	}
+
+	/// Count the weight delegated to `target` afresh on its votes in the ongoing referendums of
+	/// DAO.
+	fn update_delegated_votes(dao_id: T::DaoId, target: &T::AccountId) {
+		let delegated_weight =
+			VotingCurveOf::<T>::get(dao_id).tally_weight(DelegatedWeight::<T>::get(dao_id, target));
+		let now = Self::now();
+		let mut counted = Vec::new();
+		VotesOf::<T>::mutate(target, |votes| {
+			for h in votes.iter_mut().filter(|h| h.dao_id == dao_id) {
+				// Only the first vote of the referendum carries the delegated weight.
+				if counted.contains(&h.referendum_index) {
+					continue
+				}
+				counted.push(h.referendum_index);
+				if !Self::is_ongoing(dao_id, h.referendum_index, now) {
+					continue
+				}
+				ReferendumInfoOf::<T>::mutate(dao_id, h.referendum_index, |info| {
+					if let Some(ReferendumInfo::Ongoing(x)) = info {
+						x.tally.remove(&h.opinion, h.delegated_weight);
+						x.tally.add(&h.opinion, delegated_weight);
+					}
+				});
+				h.delegated_weight = delegated_weight;
+			}
+		});
+	}
+
+	/// The pledges of `who` that stay locked: those of its votes and of its delegations, but the
+	/// delegation in `except`.
+	fn held_pledges(who: &T::AccountId, except: Option<T::DaoId>) -> Vec<T::Pledge> {
+		VotesOf::<T>::get(who)
+			.into_iter()
+			.map(|v| v.pledge)
+			.chain(
+				Delegations::<T>::iter_prefix(who)
+					.filter(|(dao_id, _)| Some(*dao_id) != except)
+					.map(|(_, d)| d.pledge),
+			)
+			.collect()
+	}
+
+	/// End the votes of `who` that `ends` selects and release their pledges.
+	///
+	/// Returns the ended votes. A vote whose pledge can not be released is kept.
+	fn end_votes(who: &T::AccountId, ends: impl Fn(&VoteInfoOf<T>) -> bool) -> Vec<VoteInfoOf<T>> {
+		let (ending, mut votes): (Vec<_>, Vec<_>) =
+			VotesOf::<T>::get(who).into_iter().partition(|h| ends(h));
+		let delegated = Delegations::<T>::iter_prefix_values(who).map(|d| d.pledge);
+		let delegated = delegated.collect::<Vec<_>>();
+		let mut ended = Vec::new();
+		for h in ending {
+			let held = votes.iter().map(|v| v.pledge).chain(delegated.iter().copied());
+			let held = held.collect::<Vec<_>>();
+			if h.pledge.vote_end_do(who, &h.dao_id, &held).is_ok() {
+				ended.push(h);
+			} else {
+				votes.push(h);
+			}
+		}
+		VotesOf::<T>::insert(who, votes);
+		ended
+	}
+
 	fn now() -> u32 {
-		// fixme
-		// frame_system::Pallet::<T>::current_block_number()
-		100
+		frame_system::Pallet::<T>::current_block_number().saturated_into()
 	}
 
 }
@@ -4,17 +4,28 @@ use crate as square;
 use crate::Pledge;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	traits::{ConstU16, ConstU32, ConstU64},
+	dispatch::DispatchResult,
+	ensure, parameter_types,
+	traits::{
+		tokens::{
+			fungibles, nonfungibles_v2, DepositConsequence, Fortitude, Preservation, Provenance,
+			WithdrawConsequence,
+		},
+		ConstU16, ConstU32, ConstU64,
+	},
 	RuntimeDebug,
 };
 use frame_system;
-use primitives::{ids::Nft, traits::BaseCallFilter};
+use primitives::{
+	ids::Nft,
+	traits::{BaseCallFilter, CallMagnitude},
+};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	DispatchError,
+	DispatchError, TokenError,
 };
 use sp_std::result::Result;
 
@@ -71,6 +82,20 @@ impl TryFrom<Call> for u64 {
 		}
 	}
 }
+pub struct Magnitude;
+
+impl CallMagnitude<Call> for Magnitude {
+	fn magnitude(call: &Call) -> Option<u128> {
+		match call {
+			Call::Square(square::Call::set_min_vote_weight_for_every_call {
+				min_vote_weight,
+				..
+			}) => Some(*min_vote_weight as u128),
+			_ => None,
+		}
+	}
+}
+
 impl BaseCallFilter<Call> for Nft<u64> {
 	fn contains(&self, call: Call) -> bool {
 		true
@@ -96,6 +121,11 @@ impl dao::Config for Test {
 	type DaoId = u64;
 	type ConcreteId = Nft<u64>;
 	type AfterCreate = ();
+	type CallMagnitude = Magnitude;
+	type PreimageCurrency = Balances;
+	type PreimageBaseDeposit = ConstU64<1>;
+	type PreimageByteDeposit = ConstU64<1>;
+	type MaxPreimageLen = ConstU32<1024>;
 	type WeightInfo = ();
 }
 
@@ -114,7 +144,134 @@ impl Pledge<u64, u64, u64, (), u64, DispatchError> for Vote {
 		Ok((100u64, 100u64))
 	}
 
-	fn vote_end_do(&self, _who: &u64, _dao_id: &u64) -> Result<(), DispatchError> {
+	/// `Vote(0)` stands for a pledge that can not be released.
+	fn vote_end_do(&self, _who: &u64, _dao_id: &u64, _held: &[Self]) -> Result<(), DispatchError> {
+		match self.0 {
+			0 => Err(DispatchError::Other("pledge can not be released")),
+			_ => Ok(()),
+		}
+	}
+
+	fn total_voting_supply(_dao_id: &u64) -> Option<u64> {
+		Some(1000u64)
+	}
+}
+
+parameter_types! {
+	pub static NftOwners: Vec<((u64, u64), u64)> = vec![((0, 1), 10), ((0, 2), 10), ((1, 1), 10)];
+	pub static NftsLocked: Vec<(u64, u64)> = vec![];
+}
+
+/// A collection of items that can be locked against transfer.
+pub struct Nfts;
+
+impl nonfungibles_v2::Inspect<u64> for Nfts {
+	type ItemId = u64;
+	type CollectionId = u64;
+
+	fn owner(collection: &u64, item: &u64) -> Option<u64> {
+		NftOwners::get().into_iter().find(|(i, _)| i == &(*collection, *item)).map(|(_, o)| o)
+	}
+}
+
+impl nonfungibles_v2::Transfer<u64> for Nfts {
+	fn transfer(collection: &u64, item: &u64, destination: &u64) -> DispatchResult {
+		ensure!(!NftsLocked::get().contains(&(*collection, *item)), TokenError::Frozen);
+		NftOwners::mutate(|owners| {
+			owners.retain(|(i, _)| i != &(*collection, *item));
+			owners.push(((*collection, *item), *destination));
+		});
+		Ok(())
+	}
+
+	fn disable_transfer(collection: &u64, item: &u64) -> DispatchResult {
+		NftsLocked::mutate(|locked| locked.push((*collection, *item)));
+		Ok(())
+	}
+
+	fn enable_transfer(collection: &u64, item: &u64) -> DispatchResult {
+		NftsLocked::mutate(|locked| locked.retain(|i| i != &(*collection, *item)));
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub static AssetBalances: Vec<((u64, u64), u64)> = vec![((0, 10), 100), ((1, 10), 100)];
+	pub static AssetFreezes: Vec<((u64, u64), u64)> = vec![];
+}
+
+/// Fungible assets whose balances can be frozen, one freeze per asset and account.
+pub struct Assets;
+
+impl fungibles::Inspect<u64> for Assets {
+	type AssetId = u64;
+	type Balance = u64;
+
+	fn total_issuance(asset: u64) -> u64 {
+		AssetBalances::get().into_iter().filter(|(i, _)| i.0 == asset).map(|(_, b)| b).sum()
+	}
+
+	fn minimum_balance(_asset: u64) -> u64 {
+		0
+	}
+
+	fn total_balance(asset: u64, who: &u64) -> u64 {
+		Self::balance(asset, who)
+	}
+
+	fn balance(asset: u64, who: &u64) -> u64 {
+		AssetBalances::get().into_iter().find(|(i, _)| i == &(asset, *who)).map_or(0, |(_, b)| b)
+	}
+
+	fn reducible_balance(asset: u64, who: &u64, _: Preservation, _: Fortitude) -> u64 {
+		let frozen = <Self as fungibles::freeze::Inspect<u64>>::balance_frozen(asset, &(), who);
+		Self::balance(asset, who).saturating_sub(frozen)
+	}
+
+	fn can_deposit(_asset: u64, _who: &u64, _: u64, _: Provenance) -> DepositConsequence {
+		DepositConsequence::Success
+	}
+
+	fn can_withdraw(asset: u64, who: &u64, amount: u64) -> WithdrawConsequence<u64> {
+		let reducible =
+			Self::reducible_balance(asset, who, Preservation::Expendable, Fortitude::Polite);
+		match amount <= reducible {
+			true => WithdrawConsequence::Success,
+			false => WithdrawConsequence::Frozen,
+		}
+	}
+
+	fn asset_exists(asset: u64) -> bool {
+		asset < 2
+	}
+}
+
+impl fungibles::freeze::Inspect<u64> for Assets {
+	type Id = ();
+
+	fn balance_frozen(asset: u64, _id: &(), who: &u64) -> u64 {
+		AssetFreezes::get().into_iter().find(|(i, _)| i == &(asset, *who)).map_or(0, |(_, b)| b)
+	}
+
+	fn can_freeze(_asset: u64, _id: &(), _who: &u64) -> bool {
+		true
+	}
+}
+
+impl fungibles::freeze::Mutate<u64> for Assets {
+	fn set_freeze(asset: u64, id: &(), who: &u64, amount: u64) -> DispatchResult {
+		Self::thaw(asset, id, who)?;
+		AssetFreezes::mutate(|freezes| freezes.push(((asset, *who), amount)));
+		Ok(())
+	}
+
+	fn extend_freeze(asset: u64, id: &(), who: &u64, amount: u64) -> DispatchResult {
+		let frozen = <Self as fungibles::freeze::Inspect<u64>>::balance_frozen(asset, id, who);
+		Self::set_freeze(asset, id, who, amount.max(frozen))
+	}
+
+	fn thaw(asset: u64, _id: &(), who: &u64) -> DispatchResult {
+		AssetFreezes::mutate(|freezes| freezes.retain(|(i, _)| i != &(asset, *who)));
 		Ok(())
 	}
 }
@@ -123,7 +280,10 @@ impl square::Config for Test {
 	type Event = Event;
 	type Pledge = Vote;
 	type Conviction = ();
+	type VoterIdentity = ();
 	type Currency = Balances;
+	type MaxTitleLength = ConstU32<64>;
+	type MaxScheduledPerBlock = ConstU32<10>;
 	type WeightInfo = ();
 }
 
@@ -141,3 +301,95 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	.unwrap();
 	t.into()
 }
+
+/// A runtime whose DAOs are created with fungible assets.
+pub mod fungible {
+	use super::*;
+	use primitives::ids::Fungible;
+
+	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<FungibleTest>;
+	type Block = frame_system::mocking::MockBlock<FungibleTest>;
+
+	frame_support::construct_runtime!(
+		pub enum FungibleTest where
+			Block = Block,
+			NodeBlock = Block,
+			UncheckedExtrinsic = UncheckedExtrinsic,
+		{
+			System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+			DAO: dao::{ Pallet, Call, Event<T>, Storage },
+		}
+	);
+
+	impl frame_system::Config for FungibleTest {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type Origin = Origin;
+		type Call = Call;
+		type Index = u64;
+		type BlockNumber = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Header = Header;
+		type Event = Event;
+		type BlockHashCount = ConstU64<250>;
+		type DbWeight = ();
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u64>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ConstU16<42>;
+		type OnSetCode = ();
+		type MaxConsumers = ConstU32<16>;
+	}
+
+	impl TryFrom<Call> for u64 {
+		type Error = ();
+		fn try_from(_call: Call) -> Result<Self, Self::Error> {
+			Ok(0u64)
+		}
+	}
+
+	impl BaseCallFilter<Call> for Fungible<u64, Assets> {
+		fn contains(&self, _call: Call) -> bool {
+			true
+		}
+	}
+
+	impl pallet_balances::Config for FungibleTest {
+		type Balance = u64;
+		type Event = Event;
+		type DustRemoval = ();
+		type ExistentialDeposit = ConstU64<1>;
+		type AccountStore = System;
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type WeightInfo = ();
+	}
+
+	impl dao::Config for FungibleTest {
+		type Event = Event;
+		type Call = Call;
+		type CallId = u64;
+		type DaoId = u64;
+		type ConcreteId = Fungible<u64, Assets>;
+		type AfterCreate = ();
+		type CallMagnitude = ();
+		type PreimageCurrency = Balances;
+		type PreimageBaseDeposit = ConstU64<1>;
+		type PreimageByteDeposit = ConstU64<1>;
+		type MaxPreimageLen = ConstU32<1024>;
+		type WeightInfo = ();
+	}
+
+	pub fn new_test_ext() -> sp_io::TestExternalities {
+		frame_system::GenesisConfig::default().build_storage::<FungibleTest>().unwrap().into()
+	}
+}
@@ -0,0 +1,389 @@
+// Copyright 2022 daos-org.
+// This file is part of DAOS
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pledges a runtime can use for voting in referendums, and the standard conviction levels.
+
+use super::*;
+use codec::MaxEncodedLen;
+use frame_support::{
+	traits::{
+		tokens::{fungibles, nonfungibles_v2},
+		LockIdentifier, LockableCurrency, WithdrawReasons,
+	},
+	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use primitives::ids::{Fungible, Nft};
+use sp_runtime::{traits::AtLeast32BitUnsigned, RuntimeDebug, TokenError};
+use sp_std::marker::PhantomData;
+
+/// The lock on the native balance pledged in referendums.
+pub const SQUARE_LOCK_ID: LockIdentifier = *b"daos/sqr";
+
+/// How many times a vote is magnified, and for how many lock periods its pledge stays locked.
+#[derive(
+	Encode, Decode, Copy, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum Conviction {
+	/// 0.1x votes, not locked after the referendum.
+	#[default]
+	None,
+	/// 1x votes, locked for 1 period.
+	Locked1x,
+	/// 2x votes, locked for 2 periods.
+	Locked2x,
+	/// 3x votes, locked for 4 periods.
+	Locked3x,
+	/// 4x votes, locked for 8 periods.
+	Locked4x,
+	/// 5x votes, locked for 16 periods.
+	Locked5x,
+	/// 6x votes, locked for 32 periods.
+	Locked6x,
+}
+
+impl Conviction {
+	/// The number of periods the pledge stays locked.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+
+	/// The votes of every ten units pledged.
+	fn tenths(self) -> u8 {
+		match self {
+			Conviction::None => 1,
+			Conviction::Locked1x => 10,
+			Conviction::Locked2x => 20,
+			Conviction::Locked3x => 30,
+			Conviction::Locked4x => 40,
+			Conviction::Locked5x => 50,
+			Conviction::Locked6x => 60,
+		}
+	}
+
+	/// The votes of a pledged amount.
+	pub fn votes<Balance: AtLeast32BitUnsigned>(self, amount: Balance) -> Balance {
+		match self {
+			Conviction::None => amount / 10u8.into(),
+			_ => amount.saturating_mul((self.tenths() / 10).into()),
+		}
+	}
+
+	/// The blocks the pledge stays locked, each lock period lasting `period` blocks.
+	pub fn lock_duration(self, period: u32) -> u32 {
+		self.lock_periods().saturating_mul(period)
+	}
+}
+
+impl<A: From<u8>> ConvertInto<A> for Conviction {
+	fn convert_into(&self) -> A {
+		self.tenths().into()
+	}
+}
+
+/// Pledge native balance, locked through `LockableCurrency`.
+///
+/// An account has a single lock, as large as the largest pledge of its votes that have not
+/// ended, so a balance can back votes in several referendums at once. Each lock period lasts
+/// `LockPeriod` blocks.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	DefaultNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(Currency, LockPeriod))]
+pub struct CurrencyPledge<Balance: Copy + Default + Debug + Eq, Currency, LockPeriod> {
+	/// The amount pledged.
+	pub amount: Balance,
+	_phantom: PhantomData<(Currency, LockPeriod)>,
+}
+
+impl<Balance: Copy + Default + Debug + Eq, Currency, LockPeriod> Copy
+	for CurrencyPledge<Balance, Currency, LockPeriod>
+{
+}
+
+impl<Balance: Copy + Default + Debug + Eq, Currency, LockPeriod>
+	CurrencyPledge<Balance, Currency, LockPeriod>
+{
+	pub fn new(amount: Balance) -> Self {
+		Self { amount, _phantom: PhantomData }
+	}
+}
+
+impl<AccountId, DaoId, Balance, Currency, LockPeriod>
+	Pledge<Balance, AccountId, DaoId, Conviction, u32, DispatchError>
+	for CurrencyPledge<Balance, Currency, LockPeriod>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Default + Debug,
+	Currency: LockableCurrency<AccountId, Balance = Balance>,
+	LockPeriod: Get<u32>,
+{
+	fn try_vote(
+		&self,
+		who: &AccountId,
+		_dao_id: &DaoId,
+		conviction: &Conviction,
+	) -> result::Result<(Balance, u32), DispatchError> {
+		ensure!(self.amount <= Currency::free_balance(who), TokenError::FundsUnavailable);
+		Currency::extend_lock(SQUARE_LOCK_ID, who, self.amount, WithdrawReasons::TRANSFER);
+		Ok((conviction.votes(self.amount), conviction.lock_duration(LockPeriod::get())))
+	}
+
+	fn vote_end_do(
+		&self,
+		who: &AccountId,
+		_dao_id: &DaoId,
+		held: &[Self],
+	) -> result::Result<(), DispatchError> {
+		match held.iter().map(|p| p.amount).max() {
+			Some(amount) if !amount.is_zero() =>
+				Currency::set_lock(SQUARE_LOCK_ID, who, amount, WithdrawReasons::TRANSFER),
+			_ => Currency::remove_lock(SQUARE_LOCK_ID, who),
+		}
+		Ok(())
+	}
+
+	fn overlaps(&self, _other: &Self) -> bool {
+		true
+	}
+
+	fn total_voting_supply(_dao_id: &DaoId) -> Option<Balance> {
+		Some(Currency::total_issuance())
+	}
+}
+
+/// Pledge an item of the NFT collection of a DAO created with `primitives::ids::Nft`.
+///
+/// The owner votes with `ItemVotes` per item, and the item can not be transferred until all
+/// the votes it backs have ended. Each lock period lasts `LockPeriod` blocks.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	DefaultNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(Runtime, Nfts, ItemVotes, LockPeriod))]
+pub struct NftPledge<
+	CollectionId: Copy + Default + Debug + Eq,
+	ItemId: Copy + Default + Debug + Eq,
+	Runtime,
+	Nfts,
+	ItemVotes,
+	LockPeriod,
+> {
+	/// The collection of the item, which must be the one of the DAO.
+	pub collection: CollectionId,
+	/// The item pledged.
+	pub item: ItemId,
+	_phantom: PhantomData<(Runtime, Nfts, ItemVotes, LockPeriod)>,
+}
+
+impl<
+		CollectionId: Copy + Default + Debug + Eq,
+		ItemId: Copy + Default + Debug + Eq,
+		Runtime,
+		Nfts,
+		ItemVotes,
+		LockPeriod,
+	> Copy for NftPledge<CollectionId, ItemId, Runtime, Nfts, ItemVotes, LockPeriod>
+{
+}
+
+impl<
+		CollectionId: Copy + Default + Debug + Eq,
+		ItemId: Copy + Default + Debug + Eq,
+		Runtime,
+		Nfts,
+		ItemVotes,
+		LockPeriod,
+	> NftPledge<CollectionId, ItemId, Runtime, Nfts, ItemVotes, LockPeriod>
+{
+	pub fn new(collection: CollectionId, item: ItemId) -> Self {
+		Self { collection, item, _phantom: PhantomData }
+	}
+}
+
+impl<CollectionId, ItemId, Runtime, Nfts, ItemVotes, LockPeriod, Balance>
+	Pledge<Balance, Runtime::AccountId, Runtime::DaoId, Conviction, u32, DispatchError>
+	for NftPledge<CollectionId, ItemId, Runtime, Nfts, ItemVotes, LockPeriod>
+where
+	CollectionId: Copy + Default + Debug + Eq,
+	ItemId: Copy + Default + Debug + Eq,
+	Runtime: dao::Config<ConcreteId = Nft<CollectionId>>,
+	Nfts: nonfungibles_v2::Transfer<
+		Runtime::AccountId,
+		CollectionId = CollectionId,
+		ItemId = ItemId,
+	>,
+	ItemVotes: Get<Balance>,
+	LockPeriod: Get<u32>,
+	Balance: AtLeast32BitUnsigned,
+{
+	fn try_vote(
+		&self,
+		who: &Runtime::AccountId,
+		dao_id: &Runtime::DaoId,
+		conviction: &Conviction,
+	) -> result::Result<(Balance, u32), DispatchError> {
+		let Nft(collection) = dao::Pallet::<Runtime>::try_get_concrete_id(*dao_id)?;
+		ensure!(collection == self.collection, TokenError::UnknownAsset);
+		ensure!(
+			Nfts::owner(&self.collection, &self.item).as_ref() == Some(who),
+			TokenError::FundsUnavailable
+		);
+		Nfts::disable_transfer(&self.collection, &self.item)?;
+		Ok((conviction.votes(ItemVotes::get()), conviction.lock_duration(LockPeriod::get())))
+	}
+
+	fn vote_end_do(
+		&self,
+		_who: &Runtime::AccountId,
+		_dao_id: &Runtime::DaoId,
+		held: &[Self],
+	) -> result::Result<(), DispatchError> {
+		if held.iter().any(|p| p.overlaps(self)) {
+			return Ok(())
+		}
+		Nfts::enable_transfer(&self.collection, &self.item)
+	}
+
+	fn overlaps(&self, other: &Self) -> bool {
+		self.collection == other.collection && self.item == other.item
+	}
+}
+
+/// Pledge an amount of the asset of a DAO created with `primitives::ids::Fungible`, frozen
+/// through `fungibles::freeze::Mutate` under `FreezeId`.
+///
+/// An account has a single freeze of each asset, as large as the largest pledge of that asset
+/// of its votes that have not ended. Each lock period lasts `LockPeriod` blocks.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	DefaultNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(Runtime, Assets, FreezeId, LockPeriod))]
+pub struct FungiblePledge<
+	AssetId: Copy + Default + Debug + Eq,
+	Balance: Copy + Default + Debug + Eq,
+	Runtime,
+	Assets,
+	FreezeId,
+	LockPeriod,
+> {
+	/// The asset pledged, which must be the one of the DAO.
+	pub asset: AssetId,
+	/// The amount pledged.
+	pub amount: Balance,
+	_phantom: PhantomData<(Runtime, Assets, FreezeId, LockPeriod)>,
+}
+
+impl<
+		AssetId: Copy + Default + Debug + Eq,
+		Balance: Copy + Default + Debug + Eq,
+		Runtime,
+		Assets,
+		FreezeId,
+		LockPeriod,
+	> Copy for FungiblePledge<AssetId, Balance, Runtime, Assets, FreezeId, LockPeriod>
+{
+}
+
+impl<
+		AssetId: Copy + Default + Debug + Eq,
+		Balance: Copy + Default + Debug + Eq,
+		Runtime,
+		Assets,
+		FreezeId,
+		LockPeriod,
+	> FungiblePledge<AssetId, Balance, Runtime, Assets, FreezeId, LockPeriod>
+{
+	pub fn new(asset: AssetId, amount: Balance) -> Self {
+		Self { asset, amount, _phantom: PhantomData }
+	}
+}
+
+impl<AssetId, Balance, Runtime, Assets, FreezeId, LockPeriod>
+	Pledge<Balance, Runtime::AccountId, Runtime::DaoId, Conviction, u32, DispatchError>
+	for FungiblePledge<AssetId, Balance, Runtime, Assets, FreezeId, LockPeriod>
+where
+	AssetId: Copy + Default + Debug + Eq,
+	Balance: AtLeast32BitUnsigned + Copy + Default + Debug,
+	Runtime: dao::Config<ConcreteId = Fungible<AssetId, Assets>>,
+	Assets: fungibles::freeze::Mutate<Runtime::AccountId, AssetId = AssetId, Balance = Balance>,
+	FreezeId: Get<Assets::Id>,
+	LockPeriod: Get<u32>,
+{
+	fn try_vote(
+		&self,
+		who: &Runtime::AccountId,
+		dao_id: &Runtime::DaoId,
+		conviction: &Conviction,
+	) -> result::Result<(Balance, u32), DispatchError> {
+		let Fungible(asset, _) = dao::Pallet::<Runtime>::try_get_concrete_id(*dao_id)?;
+		ensure!(asset == self.asset, TokenError::UnknownAsset);
+		ensure!(self.amount <= Assets::balance(asset, who), TokenError::FundsUnavailable);
+		Assets::extend_freeze(asset, &FreezeId::get(), who, self.amount)?;
+		Ok((conviction.votes(self.amount), conviction.lock_duration(LockPeriod::get())))
+	}
+
+	fn vote_end_do(
+		&self,
+		who: &Runtime::AccountId,
+		_dao_id: &Runtime::DaoId,
+		held: &[Self],
+	) -> result::Result<(), DispatchError> {
+		let amount = held.iter().filter(|p| p.overlaps(self)).map(|p| p.amount).max();
+		match amount {
+			Some(amount) if !amount.is_zero() =>
+				Assets::set_freeze(self.asset, &FreezeId::get(), who, amount),
+			_ => Assets::thaw(self.asset, &FreezeId::get(), who),
+		}
+	}
+
+	fn overlaps(&self, other: &Self) -> bool {
+		self.asset == other.asset
+	}
+
+	fn total_voting_supply(dao_id: &Runtime::DaoId) -> Option<Balance> {
+		let Fungible(asset, _) = dao::Pallet::<Runtime>::try_get_concrete_id(*dao_id).ok()?;
+		Some(Assets::total_issuance(asset))
+	}
+}
@@ -0,0 +1,37 @@
+// Copyright 2022 daos-org.
+// This file is part of DAOS
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime api for reading square proposals and referendums.
+//!
+//! The runtime implements it by forwarding to `Pallet::proposal_metadata` and
+//! `Pallet::referendum_metadata`.
+
+use crate::{PropIndex, ReferendumIndex};
+use codec::Codec;
+use primitives::types::ProposalMetadata;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	pub trait SquareApi<DaoId>
+	where
+		DaoId: Codec,
+	{
+		/// The title and description hash of a public proposal.
+		fn proposal_metadata(dao_id: DaoId, index: PropIndex) -> Option<ProposalMetadata<Vec<u8>, Vec<u8>>>;
+
+		/// The title and description hash of a referendum.
+		fn referendum_metadata(dao_id: DaoId, index: ReferendumIndex) -> Option<ProposalMetadata<Vec<u8>, Vec<u8>>>;
+	}
+}
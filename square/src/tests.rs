@@ -3,9 +3,19 @@
 
 use super::*;
 use crate::mock::{Call, Origin, *};
-use frame_support::assert_ok;
-use primitives::ids::Nft;
-use sp_runtime::traits::BlakeTwo256;
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo};
+use frame_support::{traits::Hooks, weights::Weight};
+use primitives::{
+	constant::MAX_PRESET_NAME_LENGTH,
+	ids::{Fungible, Nft},
+};
+use crate::pledge::Conviction;
+use frame_support::traits::{tokens::nonfungibles_v2, ConstU32, ConstU64};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError, TokenError,
+};
 
 pub const ALICE: u64 = 1;
 
@@ -13,6 +23,13 @@ pub fn create_dao() {
 	dao::Pallet::<Test>::create_dao(Origin::signed(ALICE), Nft(0u64), vec![1; 4]).unwrap();
 }
 
+pub fn note(call: Call) -> PreimageRef<H256> {
+	let bytes = call.encode();
+	let preimage = PreimageRef { hash: BlakeTwo256::hash(&bytes[..]), len: bytes.len() as u32 };
+	assert_ok!(dao::Pallet::<Test>::note_preimage(Origin::signed(10u64), 0u64, bytes));
+	preimage
+}
+
 pub fn set_sudo() {
 	create_dao();
 	let proposal = Call::Sudo(sudo::Call::set_sudo_account { dao_id: 0u64, sudo_account: ALICE });
@@ -95,8 +112,10 @@ pub fn propose() {
 	assert_ok!(crate::Pallet::<Test>::propose(
 		Origin::signed(ALICE),
 		0u64,
-		Box::new(proposal),
-		0u64
+		note(proposal),
+		0u64,
+		None,
+		None
 	));
 }
 
@@ -151,6 +170,15 @@ pub fn vote() {
 	frame_system::Pallet::<Test>::set_block_number(10000);
 }
 
+/// Whether referendum 0 passes, without enacting it.
+pub fn passed() -> Result<(), DispatchError> {
+	match ReferendumInfoOf::<Test>::get(0u64, 0u32) {
+		Some(ReferendumInfo::Ongoing(x)) =>
+			crate::Pallet::<Test>::passed_call(0u64, &x).map(|_| ()),
+		_ => unreachable!(),
+	}
+}
+
 pub fn enact() {
 	vote();
 	assert!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32).is_err());
@@ -161,7 +189,7 @@ pub fn enact() {
 	frame_system::Pallet::<Test>::set_block_number(20000);
 	let ole_min_weight = MinVoteWeightOf::<Test>::get(0u64, 0u64);
 	MinVoteWeightOf::<Test>::insert(0u64, 0u64, 10000000000);
-	assert!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32).is_err());
+	assert_eq!(passed(), Err(Error::<Test>::VoteWeightTooLow.into()));
 	MinVoteWeightOf::<Test>::insert(0u64, 0u64, ole_min_weight);
 	assert_ok!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32));
 	assert!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32).is_err());
@@ -228,3 +256,752 @@ pub fn sudo_set_xxx_should_work() {
 		sudo_set_xxx();
 	});
 }
+
+#[test]
+pub fn propose_with_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dao();
+		let proposal = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 100u64,
+		});
+		let proposal = note(proposal);
+		assert!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			proposal,
+			0u64,
+			Some(vec![1; 65]),
+			None
+		)
+		.is_err());
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			proposal,
+			0u64,
+			Some(b"min vote weight".to_vec()),
+			None
+		));
+		assert_eq!(
+			crate::Pallet::<Test>::proposal_metadata(0u64, 0u32),
+			Some(ProposalMetadata { title: Some(b"min vote weight".to_vec()), description_hash: None })
+		);
+		assert!(crate::Pallet::<Test>::referendum_metadata(0u64, 0u32).is_none());
+
+		frame_system::Pallet::<Test>::set_block_number(10000);
+		assert_ok!(crate::Pallet::<Test>::open_table(Origin::signed(ALICE), 0u64));
+		assert!(crate::Pallet::<Test>::referendum_metadata(0u64, 0u32).is_some());
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		assert_ok!(crate::Pallet::<Test>::cancel_referendum(
+			Origin::signed(dao_account),
+			0u64,
+			0u32
+		));
+		assert!(crate::Pallet::<Test>::referendum_metadata(0u64, 0u32).is_none());
+	});
+}
+
+#[test]
+pub fn withdraw_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dao();
+		let proposal = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 100u64,
+		});
+		let preimage = note(proposal);
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			preimage,
+			5u64,
+			Some(b"min vote weight".to_vec()),
+			None
+		));
+		assert_ok!(crate::Pallet::<Test>::second(Origin::signed(2u64), 0u64, 0u32));
+		assert_eq!(dao::Pallet::<Test>::preimage_ref_count(0u64, preimage.hash), 1);
+		assert_noop!(
+			dao::Pallet::<Test>::unnote_preimage(Origin::signed(10u64), 0u64, preimage.hash),
+			dao::Error::<Test>::PreimageInUse
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 5);
+		assert_eq!(Balances::reserved_balance(2u64), 5);
+
+		assert!(crate::Pallet::<Test>::withdraw(Origin::signed(2u64), 0u64, 0u32).is_err());
+		assert!(crate::Pallet::<Test>::withdraw(Origin::signed(ALICE), 0u64, 1u32).is_err());
+		assert_ok!(crate::Pallet::<Test>::withdraw(Origin::signed(ALICE), 0u64, 0u32));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(2u64), 0);
+		assert!(crate::ReserveOf::<Test>::get(2u64).is_empty());
+		assert!(crate::PublicProps::<Test>::get(0u64).is_empty());
+		assert!(crate::DepositOf::<Test>::get(0u64, 0u32).is_none());
+		assert!(crate::Pallet::<Test>::proposal_metadata(0u64, 0u32).is_none());
+		assert_eq!(dao::Pallet::<Test>::preimage_ref_count(0u64, preimage.hash), 0);
+		assert!(crate::Pallet::<Test>::withdraw(Origin::signed(ALICE), 0u64, 0u32).is_err());
+	});
+}
+
+#[test]
+pub fn on_initialize_should_launch_and_enact() {
+	new_test_ext().execute_with(|| {
+		propose();
+		let at = crate::Pallet::<Test>::next_launch(0u64).unwrap();
+		assert_eq!(crate::Pallet::<Test>::launches_due(at), vec![0u64]);
+		frame_system::Pallet::<Test>::set_block_number(at.into());
+		crate::Pallet::<Test>::on_initialize(at.into());
+		assert!(crate::Pallet::<Test>::public_props(0u64).is_empty());
+		assert!(crate::Pallet::<Test>::next_launch(0u64).is_none());
+		assert!(crate::ReferendumInfoOf::<Test>::contains_key(0u64, 0u32));
+
+		assert_ok!(crate::Pallet::<Test>::vote_for_referendum(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			Vote(100u64),
+			(),
+			Opinion::AYES,
+		));
+		let due = at + VotingPeriod::<Test>::get(0u64) + EnactmentPeriod::<Test>::get(0u64);
+		assert_eq!(crate::Pallet::<Test>::enactments_due(due), vec![(0u64, 0u32)]);
+		frame_system::Pallet::<Test>::set_block_number(due.into());
+		let enact = <() as crate::weights::WeightInfo>::enact_proposal();
+		let call = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 100u64,
+		})
+		.get_dispatch_info()
+		.weight;
+		// an overdue referendum waits while the weight left does not cover its call.
+		EnactmentsOverdue::<Test>::put(EnactmentsDue::<Test>::take(due));
+		crate::Pallet::<Test>::on_idle(due.into(), enact);
+		assert_eq!(crate::Pallet::<Test>::enactments_overdue(), vec![(0u64, 0u32)]);
+		assert_eq!(
+			crate::Pallet::<Test>::on_initialize(due.into()),
+			enact.saturating_add(call)
+		);
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 0u64), 100);
+		assert!(matches!(
+			crate::ReferendumInfoOf::<Test>::get(0u64, 0u32),
+			Some(ReferendumInfo::Finished { approved: true, .. })
+		));
+
+		// Referendums over the per block limit wait until there is weight left to enact them.
+		let enactments = (0..12u32).map(|i| (0u64, i)).collect::<Vec<_>>();
+		EnactmentsDue::<Test>::insert(30000u32, enactments.clone());
+		crate::Pallet::<Test>::on_initialize(30000);
+		assert_eq!(crate::Pallet::<Test>::enactments_overdue(), enactments[10..].to_vec());
+		crate::Pallet::<Test>::on_idle(30000, Weight::zero());
+		assert_eq!(crate::Pallet::<Test>::enactments_overdue(), enactments[10..].to_vec());
+		crate::Pallet::<Test>::on_idle(30000, Weight::MAX);
+		assert!(crate::Pallet::<Test>::enactments_overdue().is_empty());
+	});
+}
+
+#[test]
+pub fn min_vote_weight_tiers_should_work() {
+	new_test_ext().execute_with(|| {
+		vote();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		assert_noop!(
+			crate::Pallet::<Test>::set_min_vote_weight_tiers(
+				Origin::signed(ALICE),
+				0u64,
+				0u64,
+				vec![(50, 1000)]
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::set_min_vote_weight_tiers(
+				Origin::signed(dao_account),
+				0u64,
+				0u64,
+				vec![(50, 1000), (50, 2000)]
+			),
+			Error::<Test>::TiersNotAscending
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::set_min_vote_weight_tiers(
+				Origin::signed(dao_account),
+				0u64,
+				0u64,
+				(0..=MAX_MAGNITUDE_TIERS as u128).map(|i| (i, 0u64)).collect()
+			),
+			Error::<Test>::TooManyTiers
+		);
+		assert_ok!(crate::Pallet::<Test>::set_min_vote_weight_tiers(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			vec![(50, 250), (100, 1000)]
+		));
+
+		// the proposal sets a minimum vote weight of 100, which is its magnitude.
+		let small = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 10u64,
+		});
+		let medium = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 99u64,
+		});
+		assert_eq!(crate::Pallet::<Test>::min_vote_weight_for(0u64, &small), 0u64);
+		assert_eq!(crate::Pallet::<Test>::min_vote_weight_for(0u64, &medium), 250u64);
+
+		frame_system::Pallet::<Test>::set_block_number(20000);
+		assert_eq!(passed(), Err(Error::<Test>::VoteWeightTooLow.into()));
+		assert_ok!(crate::Pallet::<Test>::set_min_vote_weight_tiers(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			vec![(50, 250), (100, 1)]
+		));
+		assert_ok!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32));
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 0u64), 100);
+	});
+}
+
+#[test]
+pub fn min_vote_weight_presets_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dao();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		assert_ok!(crate::Pallet::<Test>::set_min_vote_weights(
+			Origin::signed(dao_account),
+			0u64,
+			vec![(1u64, 100u64), (2u64, 200u64)]
+		));
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 1u64), 100);
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 2u64), 200);
+		assert_noop!(
+			crate::Pallet::<Test>::set_min_vote_weights(
+				Origin::signed(dao_account),
+				0u64,
+				vec![(3u64, 0u64); MAX_PRESET_CALLS as usize + 1]
+			),
+			Error::<Test>::TooManyCalls
+		);
+
+		let fast = vec![(1u64, 10u64), (2u64, 20u64)];
+		assert_noop!(
+			crate::Pallet::<Test>::register_min_vote_weight_preset(
+				Origin::signed(dao_account),
+				b"fast".to_vec(),
+				fast.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::register_min_vote_weight_preset(
+				Origin::root(),
+				vec![1; MAX_PRESET_NAME_LENGTH as usize + 1],
+				fast.clone()
+			),
+			Error::<Test>::PresetNameTooLong
+		);
+		assert_noop!(
+			crate::Pallet::<Test>::apply_min_vote_weight_preset(
+				Origin::signed(dao_account),
+				0u64,
+				b"fast".to_vec()
+			),
+			Error::<Test>::PresetMissing
+		);
+		assert_ok!(crate::Pallet::<Test>::register_min_vote_weight_preset(
+			Origin::root(),
+			b"fast".to_vec(),
+			fast
+		));
+		assert_noop!(
+			crate::Pallet::<Test>::apply_min_vote_weight_preset(
+				Origin::signed(ALICE),
+				0u64,
+				b"fast".to_vec()
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_ok!(crate::Pallet::<Test>::apply_min_vote_weight_preset(
+			Origin::signed(dao_account),
+			0u64,
+			b"fast".to_vec()
+		));
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 1u64), 10);
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 2u64), 20);
+	});
+}
+
+#[test]
+pub fn currency_pledge_should_work() {
+	new_test_ext().execute_with(|| {
+		type CurrencyPledge = crate::pledge::CurrencyPledge<u64, Balances, ConstU32<10>>;
+		let lock = || Balances::locks(&10u64).iter().map(|l| l.amount).max();
+		let (small, large) = (CurrencyPledge::new(50), CurrencyPledge::new(80));
+
+		assert_eq!(small.try_vote(&10u64, &0u64, &Conviction::None), Ok((5, 0)));
+		assert_eq!(small.try_vote(&10u64, &0u64, &Conviction::Locked3x), Ok((150, 40)));
+		assert_eq!(large.try_vote(&10u64, &0u64, &Conviction::Locked1x), Ok((80, 10)));
+		assert_eq!(lock(), Some(80));
+		assert_noop!(
+			CurrencyPledge::new(200).try_vote(&10u64, &0u64, &Conviction::Locked1x),
+			TokenError::FundsUnavailable
+		);
+
+		// The lock shrinks to the largest pledge of the votes that have not ended.
+		assert_ok!(large.vote_end_do(&10u64, &0u64, &[small, small]));
+		assert_eq!(lock(), Some(50));
+		assert_ok!(small.vote_end_do(&10u64, &0u64, &[small]));
+		assert_eq!(lock(), Some(50));
+		assert_ok!(small.vote_end_do(&10u64, &0u64, &[]));
+		assert_eq!(lock(), None);
+	});
+}
+
+#[test]
+pub fn nft_pledge_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dao();
+		type NftPledge = crate::pledge::NftPledge<u64, u64, Test, Nfts, ConstU64<10>, ConstU32<10>>;
+		type Weighed = dyn Fn(&NftPledge, u64, Conviction) -> Result<(u64, u32), DispatchError>;
+		let try_vote: &Weighed = &|p, who, conviction| p.try_vote(&who, &0u64, &conviction);
+		let vote_end_do = |p: NftPledge, held: &[NftPledge]| {
+			Pledge::<u64, _, _, Conviction, _, _>::vote_end_do(&p, &10u64, &0u64, held)
+		};
+		let (first, second) = (NftPledge::new(0, 1), NftPledge::new(0, 2));
+
+		assert_noop!(
+			try_vote(&NftPledge::new(1, 1), 10u64, Conviction::Locked1x),
+			TokenError::UnknownAsset
+		);
+		assert_noop!(try_vote(&first, 20u64, Conviction::Locked1x), TokenError::FundsUnavailable);
+		assert_eq!(try_vote(&first, 10u64, Conviction::Locked2x), Ok((20, 20)));
+		assert_eq!(try_vote(&second, 10u64, Conviction::None), Ok((1, 0)));
+		assert!(Pledge::<u64, _, _, Conviction, _, _>::overlaps(&first, &NftPledge::new(0, 1)));
+		assert_noop!(
+			<Nfts as nonfungibles_v2::Transfer<u64>>::transfer(&0, &1, &20u64),
+			TokenError::Frozen
+		);
+
+		// An item stays locked while another vote still holds it.
+		assert_ok!(vote_end_do(first, &[second, first]));
+		assert_eq!(NftsLocked::get(), vec![(0, 1), (0, 2)]);
+		assert_ok!(vote_end_do(first, &[second]));
+		assert_eq!(NftsLocked::get(), vec![(0, 2)]);
+		assert_ok!(<Nfts as nonfungibles_v2::Transfer<u64>>::transfer(&0, &1, &20u64));
+	});
+}
+
+#[test]
+pub fn fungible_pledge_should_work() {
+	fungible::new_test_ext().execute_with(|| {
+		type Runtime = fungible::FungibleTest;
+		let origin = || fungible::Origin::signed(ALICE);
+		// a DAO can only be created for an asset that exists.
+		assert_noop!(
+			dao::Pallet::<Runtime>::create_dao(origin(), Fungible::new(2u64), vec![1; 4]),
+			TokenError::UnknownAsset
+		);
+		assert_ok!(dao::Pallet::<Runtime>::create_dao(origin(), Fungible::new(0u64), vec![1; 4]));
+		type FungiblePledge =
+			crate::pledge::FungiblePledge<u64, u64, Runtime, Assets, (), ConstU32<10>>;
+		let frozen = || AssetFreezes::get().into_iter().find(|f| f.0 == (0, 10)).map(|f| f.1);
+		let (small, large) = (FungiblePledge::new(0, 50), FungiblePledge::new(0, 80));
+
+		assert_noop!(
+			FungiblePledge::new(1, 50).try_vote(&10u64, &0u64, &Conviction::Locked1x),
+			TokenError::UnknownAsset
+		);
+		assert_noop!(
+			FungiblePledge::new(0, 200).try_vote(&10u64, &0u64, &Conviction::Locked1x),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(small.try_vote(&10u64, &0u64, &Conviction::Locked3x), Ok((150, 40)));
+		assert_eq!(large.try_vote(&10u64, &0u64, &Conviction::None), Ok((8, 0)));
+		assert_eq!(frozen(), Some(80));
+
+		// The freeze shrinks to the largest pledge of the asset of the votes that have not ended.
+		assert_ok!(large.vote_end_do(&10u64, &0u64, &[small, FungiblePledge::new(1, 90)]));
+		assert_eq!(frozen(), Some(50));
+		assert_ok!(small.vote_end_do(&10u64, &0u64, &[FungiblePledge::new(1, 90)]));
+		assert_eq!(frozen(), None);
+	});
+}
+
+#[test]
+pub fn quadratic_voting_should_work() {
+	new_test_ext().execute_with(|| {
+		open_table();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let tally = || match ReferendumInfoOf::<Test>::get(0u64, 0u32) {
+			Some(ReferendumInfo::Ongoing(x)) => (x.tally.ayes, x.tally.nays),
+			_ => unreachable!(),
+		};
+		let vote = |who: u64, opinion| {
+			crate::Pallet::<Test>::vote_for_referendum(
+				Origin::signed(who),
+				0u64,
+				0u32,
+				Vote(100u64),
+				(),
+				opinion,
+			)
+		};
+		assert_noop!(
+			crate::Pallet::<Test>::set_voting_curve(
+				Origin::signed(ALICE),
+				0u64,
+				VotingCurve::Quadratic
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_ok!(crate::Pallet::<Test>::set_voting_curve(
+			Origin::signed(dao_account),
+			0u64,
+			VotingCurve::Quadratic
+		));
+
+		// The mock pledge weighs 100, whose square root counts.
+		assert_ok!(vote(ALICE, Opinion::AYES));
+		assert_eq!(tally(), (10, 0));
+		assert_noop!(vote(ALICE, Opinion::AYES), Error::<Test>::IdentityAlreadyVoted);
+		assert_ok!(vote(2u64, Opinion::NAYS));
+		assert_eq!(tally(), (10, 10));
+
+		assert_ok!(crate::Pallet::<Test>::cancel_vote(Origin::signed(ALICE), 0u64, 0u32));
+		assert_eq!(tally(), (0, 10));
+		assert_eq!(QuadraticVoters::<Test>::get((0u64, 0u32, ALICE)), None);
+		assert_ok!(vote(ALICE, Opinion::NAYS));
+		assert_eq!(tally(), (0, 20));
+
+		// A vote whose pledge can not be released stays, and so does its identity.
+		assert_ok!(crate::Pallet::<Test>::vote_for_referendum(
+			Origin::signed(3u64),
+			0u64,
+			0u32,
+			Vote(0u64),
+			(),
+			Opinion::AYES,
+		));
+		assert_ok!(crate::Pallet::<Test>::cancel_vote(Origin::signed(3u64), 0u64, 0u32));
+		assert_eq!(tally(), (10, 20));
+		assert_eq!(QuadraticVoters::<Test>::get((0u64, 0u32, 3u64)), Some(3u64));
+
+		// The voters of a referendum are cleared once it is finished.
+		assert_ok!(crate::Pallet::<Test>::cancel_referendum(
+			Origin::signed(dao_account),
+			0u64,
+			0u32
+		));
+		assert_eq!(QuadraticVoters::<Test>::iter_prefix((0u64, 0u32)).count(), 0);
+	});
+}
+
+#[test]
+pub fn approval_rule_should_work() {
+	new_test_ext().execute_with(|| {
+		vote();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let rule = |turnout, approval| ApprovalRule {
+			turnout: Perbill::from_percent(turnout),
+			approval: Perbill::from_percent(approval),
+		};
+		assert_noop!(
+			crate::Pallet::<Test>::set_approval_rule(
+				Origin::signed(ALICE),
+				0u64,
+				0u64,
+				Some(rule(20, 66))
+			),
+			dao::Error::<Test>::BadOrigin
+		);
+
+		// 300 of the supply of 1000 voted, 200 of them ayes.
+		assert_ok!(crate::Pallet::<Test>::set_approval_rule(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			Some(rule(40, 66))
+		));
+		frame_system::Pallet::<Test>::set_block_number(20000);
+		assert_eq!(passed(), Err(Error::<Test>::TurnoutTooLow.into()));
+		assert!(!rule(20, 67).is_approved(200u64, 100u64));
+		assert_ok!(crate::Pallet::<Test>::set_approval_rule(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			Some(rule(20, 66))
+		));
+		assert_ok!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32));
+		assert!(matches!(
+			ReferendumInfoOf::<Test>::get(0u64, 0u32),
+			Some(ReferendumInfo::Finished { approved: true, .. })
+		));
+	});
+}
+
+#[test]
+pub fn missed_turnout_should_finish_referendum() {
+	new_test_ext().execute_with(|| {
+		create_dao();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let proposal = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 100u64,
+		});
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(ALICE),
+			0u64,
+			note(proposal),
+			5u64,
+			None,
+			None
+		));
+		assert_ok!(crate::Pallet::<Test>::second(Origin::signed(2u64), 0u64, 0u32));
+		let at = crate::Pallet::<Test>::next_launch(0u64).unwrap();
+		frame_system::Pallet::<Test>::set_block_number(at.into());
+		crate::Pallet::<Test>::on_initialize(at.into());
+		assert_ok!(crate::Pallet::<Test>::vote_for_referendum(
+			Origin::signed(ALICE),
+			0u64,
+			0u32,
+			Vote(100u64),
+			(),
+			Opinion::AYES,
+		));
+		// 100 of the supply of 1000 voted.
+		assert_ok!(crate::Pallet::<Test>::set_approval_rule(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			Some(ApprovalRule {
+				turnout: Perbill::from_percent(40),
+				approval: Perbill::from_percent(50),
+			})
+		));
+
+		let due = at + VotingPeriod::<Test>::get(0u64) + EnactmentPeriod::<Test>::get(0u64);
+		frame_system::Pallet::<Test>::set_block_number(due.into());
+		crate::Pallet::<Test>::on_initialize(due.into());
+		assert!(matches!(
+			crate::ReferendumInfoOf::<Test>::get(0u64, 0u32),
+			Some(ReferendumInfo::Finished { approved: false, .. })
+		));
+		assert_eq!(MinVoteWeightOf::<Test>::get(0u64, 0u64), 0);
+		assert!(crate::ReferendumDepositOf::<Test>::get(0u64, 0u32).is_none());
+		// The proposer gets the deposit back, the seconder waits for `ReservePeriod`.
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(2u64), 5);
+		assert_noop!(
+			crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32),
+			Error::<Test>::ReferendumFinished
+		);
+	});
+}
+
+#[test]
+pub fn delegation_should_work() {
+	new_test_ext().execute_with(|| {
+		vote();
+		let tally = || match ReferendumInfoOf::<Test>::get(0u64, 0u32) {
+			Some(ReferendumInfo::Ongoing(x)) => (x.tally.ayes, x.tally.nays),
+			_ => unreachable!(),
+		};
+		let vote = |who: u64, opinion| {
+			crate::Pallet::<Test>::vote_for_referendum(
+				Origin::signed(who),
+				0u64,
+				0u32,
+				Vote(100u64),
+				(),
+				opinion,
+			)
+		};
+		let delegate = |who: u64| {
+			crate::Pallet::<Test>::delegate(Origin::signed(who), 0u64, ALICE, Vote(100u64), ())
+		};
+		assert_eq!(tally(), (200, 100));
+
+		assert_ok!(vote(3u64, Opinion::NAYS));
+		assert_noop!(delegate(3u64), Error::<Test>::AlreadyVoting);
+		assert_noop!(delegate(ALICE), Error::<Test>::SelfDelegation);
+
+		// The weight flows to the first vote of ALICE, and is counted once.
+		assert_ok!(delegate(2u64));
+		assert_eq!(tally(), (300, 200));
+		assert_noop!(vote(2u64, Opinion::AYES), Error::<Test>::Delegating);
+		assert_noop!(delegate(2u64), Error::<Test>::AlreadyDelegating);
+		let delegate_to = |who: u64, target: u64| {
+			crate::Pallet::<Test>::delegate(Origin::signed(who), 0u64, target, Vote(100u64), ())
+		};
+		assert_noop!(delegate_to(20u64, 2u64), Error::<Test>::DelegationChain);
+		assert_noop!(delegate_to(ALICE, 20u64), Error::<Test>::DelegationChain);
+		assert_ok!(vote(ALICE, Opinion::NAYS));
+		assert_eq!(tally(), (300, 300));
+
+		assert_ok!(crate::Pallet::<Test>::undelegate(Origin::signed(2u64), 0u64));
+		assert_eq!(tally(), (200, 300));
+		assert_eq!(DelegatedWeight::<Test>::get(0u64, ALICE), 0);
+		assert_noop!(
+			crate::Pallet::<Test>::undelegate(Origin::signed(2u64), 0u64),
+			Error::<Test>::NotDelegating
+		);
+		// A revoked delegation stays until its pledge is unlocked.
+		assert_noop!(delegate(2u64), Error::<Test>::AlreadyDelegating);
+		assert_ok!(vote(2u64, Opinion::AYES));
+		assert_eq!(tally(), (300, 300));
+
+		assert_ok!(crate::Pallet::<Test>::unlock(Origin::signed(2u64)));
+		assert!(Delegations::<Test>::get(2u64, 0u64).is_some());
+		frame_system::Pallet::<Test>::set_block_number(20000);
+		assert_ok!(crate::Pallet::<Test>::unlock(Origin::signed(2u64)));
+		assert!(Delegations::<Test>::get(2u64, 0u64).is_none());
+		assert!(VotesOf::<Test>::get(2u64).is_empty());
+	});
+}
+
+#[test]
+pub fn split_and_abstain_votes_should_work() {
+	new_test_ext().execute_with(|| {
+		open_table();
+		let tally = || match ReferendumInfoOf::<Test>::get(0u64, 0u32) {
+			Some(ReferendumInfo::Ongoing(x)) => (x.tally.ayes, x.tally.nays, x.tally.abstentions),
+			_ => unreachable!(),
+		};
+		let vote = |who: u64, opinion| {
+			crate::Pallet::<Test>::vote_for_referendum(
+				Origin::signed(who),
+				0u64,
+				0u32,
+				Vote(100u64),
+				(),
+				opinion,
+			)
+		};
+
+		assert_noop!(vote(3u64, Opinion::Split { aye: 0, nay: 0 }), Error::<Test>::EmptySplit);
+		assert_ok!(vote(ALICE, Opinion::Split { aye: 3, nay: 1 }));
+		assert_ok!(vote(2u64, Opinion::Abstain));
+		assert_eq!(tally(), (75, 25, 100));
+
+		assert_ok!(crate::Pallet::<Test>::cancel_vote(Origin::signed(ALICE), 0u64, 0u32));
+		assert_eq!(tally(), (0, 0, 100));
+		assert_ok!(vote(ALICE, Opinion::Split { aye: 1, nay: 3 }));
+		assert_eq!(tally(), (25, 75, 100));
+
+		// Abstentions reach the turnout, but do not approve.
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let rule = ApprovalRule {
+			turnout: Perbill::from_percent(20),
+			approval: Perbill::from_percent(50),
+		};
+		assert_ok!(crate::Pallet::<Test>::set_approval_rule(
+			Origin::signed(dao_account),
+			0u64,
+			0u64,
+			Some(rule)
+		));
+		frame_system::Pallet::<Test>::set_block_number(20000);
+		assert_ok!(crate::Pallet::<Test>::enact_proposal(Origin::signed(ALICE), 0u64, 0u32));
+		assert!(matches!(
+			ReferendumInfoOf::<Test>::get(0u64, 0u32),
+			Some(ReferendumInfo::Finished { approved: false, .. })
+		));
+
+		assert_ok!(crate::Pallet::<Test>::unlock(Origin::signed(ALICE)));
+		assert_ok!(crate::Pallet::<Test>::unlock(Origin::signed(2u64)));
+		assert!(VotesOf::<Test>::get(ALICE).is_empty());
+		assert!(VotesOf::<Test>::get(2u64).is_empty());
+	});
+}
+
+#[test]
+pub fn cancel_and_blacklist_should_work() {
+	new_test_ext().execute_with(|| {
+		create_dao();
+		let dao_account = dao::Daos::<Test>::get(0u64).unwrap().dao_account_id;
+		let proposal = Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 100u64,
+		});
+		let preimage = note(proposal);
+		let propose = |who: u64| {
+			crate::Pallet::<Test>::propose(Origin::signed(who), 0u64, preimage, 5u64, None, None)
+		};
+		assert_ok!(propose(ALICE));
+		assert_ok!(crate::Pallet::<Test>::second(Origin::signed(20u64), 0u64, 0u32));
+		frame_system::Pallet::<Test>::set_block_number(10000);
+		assert_ok!(crate::Pallet::<Test>::open_table(Origin::signed(ALICE), 0u64));
+		// another proposal of the same deposit, which the cancellation must leave alone.
+		let other = note(Call::Square(crate::Call::set_min_vote_weight_for_every_call {
+			dao_id: 0u64,
+			call_id: 0u64,
+			min_vote_weight: 200u64,
+		}));
+		assert_ok!(crate::Pallet::<Test>::propose(
+			Origin::signed(10u64),
+			0u64,
+			other,
+			5u64,
+			None,
+			None
+		));
+		assert_ok!(crate::Pallet::<Test>::second(Origin::signed(20u64), 0u64, 1u32));
+
+		assert_noop!(
+			crate::Pallet::<Test>::cancel_referendum(Origin::signed(ALICE), 0u64, 0u32),
+			dao::Error::<Test>::BadOrigin
+		);
+		assert_ok!(crate::Pallet::<Test>::cancel_referendum(
+			Origin::signed(dao_account),
+			0u64,
+			0u32
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(20u64), 5);
+		assert_eq!(crate::ReserveOf::<Test>::get(20u64), vec![(5u64, 10900u32, (0u64, 1u32))]);
+		assert!(matches!(
+			crate::ReferendumInfoOf::<Test>::get(0u64, 0u32),
+			Some(ReferendumInfo::Finished { approved: false, .. })
+		));
+		assert_noop!(
+			crate::Pallet::<Test>::cancel_referendum(Origin::signed(dao_account), 0u64, 0u32),
+			Error::<Test>::ReferendumFinished
+		);
+		assert_ok!(crate::Pallet::<Test>::withdraw(Origin::signed(10u64), 0u64, 1u32));
+
+		assert_ok!(propose(ALICE));
+		assert_ok!(crate::Pallet::<Test>::second(Origin::signed(20u64), 0u64, 2u32));
+		let total = Balances::total_balance(&ALICE);
+		assert_ok!(crate::Pallet::<Test>::set_blacklist_period(
+			Origin::signed(dao_account),
+			0u64,
+			100u32
+		));
+		assert_ok!(crate::Pallet::<Test>::blacklist_proposal(
+			Origin::signed(dao_account),
+			0u64,
+			preimage.hash,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(20u64), 0);
+		assert!(crate::ReserveOf::<Test>::get(20u64).is_empty());
+		assert_eq!(Balances::total_balance(&ALICE), total - 5);
+		assert!(crate::PublicProps::<Test>::get(0u64).is_empty());
+		assert!(crate::DepositOf::<Test>::get(0u64, 2u32).is_none());
+		assert_eq!(crate::Pallet::<Test>::blacklist(0u64, preimage.hash), Some(10100u32));
+
+		assert_noop!(propose(10u64), Error::<Test>::ProposalBlacklisted);
+		frame_system::Pallet::<Test>::set_block_number(10100);
+		assert_ok!(propose(10u64));
+		assert!(crate::Pallet::<Test>::blacklist(0u64, preimage.hash).is_none());
+	});
+}
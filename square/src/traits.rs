@@ -1,14 +1,48 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use super::*;
 
-pub trait Pledge<VoteWeight, AccountId, DaoId, Convivtion, BlockNumber, DispatchError> {
+pub trait Pledge<VoteWeight, AccountId, DaoId, Convivtion, BlockNumber, DispatchError>:
+	Sized
+{
 	fn try_vote(
 		&self,
 		who: &AccountId,
 		dao_id: &DaoId,
 		conviction: &Convivtion,
 	) -> result::Result<(VoteWeight, BlockNumber), DispatchError>;
-	fn vote_end_do(&self, who: &AccountId, dao_id: &DaoId) -> result::Result<(), DispatchError>;
+	/// Release what the pledge locked. `held` are the pledges of the other votes of `who`
+	/// that have not ended, which must stay locked.
+	fn vote_end_do(
+		&self,
+		who: &AccountId,
+		dao_id: &DaoId,
+		held: &[Self],
+	) -> result::Result<(), DispatchError>;
+	/// Whether the two pledges hold something in common, which can not be counted twice in a
+	/// referendum.
+	fn overlaps(&self, _other: &Self) -> bool {
+		false
+	}
+	/// The votes of everything that can be pledged in a DAO at 1x conviction, which turnout
+	/// is measured against, or `None` if it is not known.
+	fn total_voting_supply(_dao_id: &DaoId) -> Option<VoteWeight> {
+		None
+	}
+}
+
+/// The identity an account votes as, so that an identity with several accounts votes once.
+pub trait VoterIdentity<AccountId> {
+	type Identity: frame_support::Parameter;
+	/// The identity of `who`, or `None` if it has none and can not vote.
+	fn identity_of(who: &AccountId) -> Option<Self::Identity>;
+}
+
+/// Every account is an identity of its own.
+impl<AccountId: frame_support::Parameter> VoterIdentity<AccountId> for () {
+	type Identity = AccountId;
+	fn identity_of(who: &AccountId) -> Option<AccountId> {
+		Some(who.clone())
+	}
 }
 
 pub trait ConvertInto<A> {
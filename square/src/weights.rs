@@ -31,11 +31,14 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn propose() -> Weight;
     fn second() -> Weight;
+    fn withdraw() -> Weight;
     fn open_table() -> Weight;
     fn vote_for_referendum() -> Weight;
     fn cancel_vote() -> Weight;
     fn enact_proposal() -> Weight;
     fn unlock() -> Weight;
+    fn delegate() -> Weight;
+    fn undelegate() -> Weight;
     fn set_min_vote_weight_for_every_call() -> Weight;
     fn set_max_public_props() -> Weight;
     fn set_launch_period() -> Weight;
@@ -43,6 +46,15 @@ pub trait WeightInfo {
     fn set_voting_period() -> Weight;
     fn set_rerserve_period() -> Weight;
     fn set_enactment_period() -> Weight;
+    fn set_min_vote_weight_tiers() -> Weight;
+    fn set_min_vote_weights() -> Weight;
+    fn set_voting_curve() -> Weight;
+    fn set_approval_rule() -> Weight;
+    fn register_min_vote_weight_preset() -> Weight;
+    fn apply_min_vote_weight_preset() -> Weight;
+    fn cancel_referendum() -> Weight;
+    fn blacklist_proposal() -> Weight;
+    fn set_blacklist_period() -> Weight;
 }
 
 /// Weights for daos_square using the Substrate node and recommended hardware.
@@ -55,6 +67,7 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare PublicProps (r:1 w:1)
             // Storage: DaoSquare MaxPublicProps (r:1 w:0)
             // Storage: DaoSquare DepositOf (r:0 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:0 w:1)
         fn propose() -> Weight {
                 Weight::from_all(2000_0000)
         }
@@ -64,6 +77,14 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare ReserveOf (r:1 w:1)
         fn second() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare PublicProps (r:1 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:0 w:1)
+            // Storage: DaoSquare DepositOf (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: DaoSquare ReserveOf (r:1 w:1)
+        fn withdraw() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: DaoSquare LaunchTag (r:1 w:0)
             // Storage: CreateDao Daos (r:1 w:0)
@@ -74,6 +95,8 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare EnactmentPeriod (r:1 w:0)
             // Storage: DaoSquare ReferendumCount (r:1 w:1)
             // Storage: DaoSquare ReferendumInfoOf (r:0 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumMetadataOf (r:0 w:1)
         fn open_table() -> Weight {
             Weight::from_all(2000_0000)
         }
@@ -96,8 +119,26 @@ pub struct DaosWeight<T>(PhantomData<T>);
         }
             // Storage: DaoSquare ReserveOf (r:1 w:1)
             // Storage: DaoSquare VotesOf (r:1 w:1)
+            // Storage: DaoSquare Delegations (r:1 w:1)
         fn unlock() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare Delegations (r:1 w:1)
+            // Storage: DaoSquare VotesOf (r:2 w:1)
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare DelegatedWeight (r:2 w:1)
+            // Storage: DaoSquare VotingCurveOf (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:2 w:1)
+        fn delegate() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare Delegations (r:1 w:1)
+            // Storage: DaoSquare DelegatedWeight (r:2 w:1)
+            // Storage: DaoSquare VotingCurveOf (r:1 w:0)
+            // Storage: DaoSquare VotesOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:2 w:1)
+        fn undelegate() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
             // Storage: DaoSquare MinVoteWeightOf (r:0 w:1)
@@ -134,6 +175,62 @@ pub struct DaosWeight<T>(PhantomData<T>);
         fn set_enactment_period() -> Weight {
             Weight::from_all(2000_0000)
         }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightTiers (r:0 w:1)
+        fn set_min_vote_weight_tiers() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightOf (r:0 w:64)
+        fn set_min_vote_weights() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare VotingCurveOf (r:0 w:1)
+        fn set_voting_curve() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ApprovalRuleOf (r:0 w:1)
+        fn set_approval_rule() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare MinVoteWeightPresets (r:0 w:1)
+        fn register_min_vote_weight_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightPresets (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightOf (r:0 w:64)
+        fn apply_min_vote_weight_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumDepositOf (r:1 w:1)
+            // Storage: DaoSquare ReserveOf (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn cancel_referendum() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumDepositOf (r:1 w:1)
+            // Storage: DaoSquare PublicProps (r:1 w:1)
+            // Storage: DaoSquare DepositOf (r:1 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:0 w:1)
+            // Storage: DaoSquare ReserveOf (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: DaoSquare BlacklistPeriod (r:1 w:0)
+            // Storage: DaoSquare Blacklist (r:0 w:1)
+        fn blacklist_proposal() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare BlacklistPeriod (r:0 w:1)
+        fn set_blacklist_period() -> Weight {
+            Weight::from_all(2000_0000)
+        }
     }
 
     // For backwards compatibility and tests
@@ -145,6 +242,7 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare PublicProps (r:1 w:1)
             // Storage: DaoSquare MaxPublicProps (r:1 w:0)
             // Storage: DaoSquare DepositOf (r:0 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:0 w:1)
         fn propose() -> Weight {
             Weight::from_all(2000_0000)
         }
@@ -154,6 +252,14 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare ReserveOf (r:1 w:1)
         fn second() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare PublicProps (r:1 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:0 w:1)
+            // Storage: DaoSquare DepositOf (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: DaoSquare ReserveOf (r:1 w:1)
+        fn withdraw() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: DaoSquare LaunchTag (r:1 w:0)
             // Storage: CreateDao Daos (r:1 w:0)
@@ -164,6 +270,8 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare EnactmentPeriod (r:1 w:0)
             // Storage: DaoSquare ReferendumCount (r:1 w:1)
             // Storage: DaoSquare ReferendumInfoOf (r:0 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumMetadataOf (r:0 w:1)
         fn open_table() -> Weight {
             Weight::from_all(2000_0000)
         }
@@ -186,8 +294,26 @@ pub struct DaosWeight<T>(PhantomData<T>);
         }
             // Storage: DaoSquare ReserveOf (r:1 w:1)
             // Storage: DaoSquare VotesOf (r:1 w:1)
+            // Storage: DaoSquare Delegations (r:1 w:1)
         fn unlock() -> Weight {
             Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare Delegations (r:1 w:1)
+            // Storage: DaoSquare VotesOf (r:2 w:1)
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare DelegatedWeight (r:2 w:1)
+            // Storage: DaoSquare VotingCurveOf (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:2 w:1)
+        fn delegate() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare Delegations (r:1 w:1)
+            // Storage: DaoSquare DelegatedWeight (r:2 w:1)
+            // Storage: DaoSquare VotingCurveOf (r:1 w:0)
+            // Storage: DaoSquare VotesOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:2 w:1)
+        fn undelegate() -> Weight {
+            Weight::from_all(2000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
             // Storage: DaoSquare MinVoteWeightOf (r:0 w:1)
@@ -224,4 +350,60 @@ pub struct DaosWeight<T>(PhantomData<T>);
         fn set_enactment_period() -> Weight {
             Weight::from_all(2000_0000)
         }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightTiers (r:0 w:1)
+        fn set_min_vote_weight_tiers() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightOf (r:0 w:64)
+        fn set_min_vote_weights() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare VotingCurveOf (r:0 w:1)
+        fn set_voting_curve() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ApprovalRuleOf (r:0 w:1)
+        fn set_approval_rule() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: DaoSquare MinVoteWeightPresets (r:0 w:1)
+        fn register_min_vote_weight_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightPresets (r:1 w:0)
+            // Storage: DaoSquare MinVoteWeightOf (r:0 w:64)
+        fn apply_min_vote_weight_preset() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumDepositOf (r:1 w:1)
+            // Storage: DaoSquare ReserveOf (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn cancel_referendum() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumDepositOf (r:1 w:1)
+            // Storage: DaoSquare PublicProps (r:1 w:1)
+            // Storage: DaoSquare DepositOf (r:1 w:1)
+            // Storage: DaoSquare ProposalMetadataOf (r:0 w:1)
+            // Storage: DaoSquare ReserveOf (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+            // Storage: DaoSquare BlacklistPeriod (r:1 w:0)
+            // Storage: DaoSquare Blacklist (r:0 w:1)
+        fn blacklist_proposal() -> Weight {
+            Weight::from_all(2000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare BlacklistPeriod (r:0 w:1)
+        fn set_blacklist_period() -> Weight {
+            Weight::from_all(2000_0000)
+        }
    }
\ No newline at end of file